@@ -297,7 +297,7 @@ pub fn verify_membership<H: HostFunctionsProvider>(
         Err(CommitmentError::MissingCommitmentPrefix)?;
     }
 
-    let merkle_path = MerklePath::new(vec![prefix.as_bytes().to_vec().into(), path]);
+    let merkle_path = MerklePath::with_prefix(prefix, path);
 
     let merkle_proof = MerkleProof::try_from(proof)?;
 
@@ -318,7 +318,7 @@ pub fn verify_non_membership<H: HostFunctionsProvider>(
     root: &CommitmentRoot,
     path: PathBytes,
 ) -> Result<(), ClientError> {
-    let merkle_path = MerklePath::new(vec![prefix.as_bytes().to_vec().into(), path]);
+    let merkle_path = MerklePath::with_prefix(prefix, path);
 
     let merkle_proof = MerkleProof::try_from(proof)?;
 
@@ -4,6 +4,10 @@ use core::cmp::Ordering;
 use core::str::FromStr;
 
 use ibc_core_host_types::error::DecodingError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::{
+    ClientConsensusStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
+};
 use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 use ibc_proto::Protobuf;
@@ -178,6 +182,72 @@ impl FromStr for Height {
     }
 }
 
+/// Conversions between [`Height`] and the client paths keyed by a consensus
+/// height, which store its revision number and revision height separately.
+pub trait ConsensusHeightPath: Sized {
+    /// Constructs the path of the given client at the given consensus height.
+    fn from_height(client_id: ClientId, height: Height) -> Self;
+
+    /// Returns the consensus height of the path.
+    ///
+    /// Fails if the stored revision height is zero, which is not a valid
+    /// [`Height`]. A zero revision number, as used by chains which never
+    /// upgraded, is valid.
+    fn height(&self) -> Result<Height, ClientError>;
+}
+
+impl ConsensusHeightPath for ClientConsensusStatePath {
+    fn from_height(client_id: ClientId, height: Height) -> Self {
+        Self::new(client_id, height.revision_number, height.revision_height)
+    }
+
+    fn height(&self) -> Result<Height, ClientError> {
+        Height::new(self.revision_number, self.revision_height)
+    }
+}
+
+impl ConsensusHeightPath for ClientUpdateTimePath {
+    fn from_height(client_id: ClientId, height: Height) -> Self {
+        Self::new(client_id, height.revision_number, height.revision_height)
+    }
+
+    fn height(&self) -> Result<Height, ClientError> {
+        Height::new(self.revision_number, self.revision_height)
+    }
+}
+
+impl ConsensusHeightPath for ClientUpdateHeightPath {
+    fn from_height(client_id: ClientId, height: Height) -> Self {
+        Self::new(client_id, height.revision_number, height.revision_height)
+    }
+
+    fn height(&self) -> Result<Height, ClientError> {
+        Height::new(self.revision_number, self.revision_height)
+    }
+}
+
+/// A typed view of the [`Path`] variants keyed by a client and a consensus
+/// height.
+pub trait AsClientConsensus {
+    /// Returns the client identifier and consensus height of a consensus
+    /// state, update time or update height path.
+    ///
+    /// Returns `None` for any other path, or if the stored revision height is
+    /// zero, which is not a valid [`Height`].
+    fn as_client_consensus(&self) -> Option<(&ClientId, Height)>;
+}
+
+impl AsClientConsensus for Path {
+    fn as_client_consensus(&self) -> Option<(&ClientId, Height)> {
+        match self {
+            Path::ClientConsensusState(path) => Some((&path.client_id, path.height().ok()?)),
+            Path::ClientUpdateTime(path) => Some((&path.client_id, path.height().ok()?)),
+            Path::ClientUpdateHeight(path) => Some((&path.client_id, path.height().ok()?)),
+            _ => None,
+        }
+    }
+}
+
 #[test]
 fn test_valid_height() {
     assert_eq!(
@@ -212,3 +282,61 @@ fn test_invalid_height() {
     let decoding_err = decoding_err.to_string();
     assert!(decoding_err.contains("height `` not properly formatted"));
 }
+
+#[test]
+fn test_consensus_height_path() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let height = Height::new(1, 10).unwrap();
+
+    let path = ClientConsensusStatePath::from_height(client_id.clone(), height);
+    assert_eq!(
+        path,
+        ClientConsensusStatePath::new(client_id.clone(), 1, 10)
+    );
+    assert_eq!(path.height().unwrap(), height);
+
+    let path = ClientUpdateTimePath::from_height(client_id.clone(), height);
+    assert_eq!(path, ClientUpdateTimePath::new(client_id.clone(), 1, 10));
+    assert_eq!(path.height().unwrap(), height);
+
+    let path = ClientUpdateHeightPath::from_height(client_id.clone(), height);
+    assert_eq!(path, ClientUpdateHeightPath::new(client_id.clone(), 1, 10));
+    assert_eq!(path.height().unwrap(), height);
+
+    assert_eq!(
+        ClientConsensusStatePath::new(client_id.clone(), 0, 100)
+            .height()
+            .unwrap(),
+        Height::new(0, 100).unwrap()
+    );
+    assert!(ClientConsensusStatePath::new(client_id, 1, 0)
+        .height()
+        .is_err());
+}
+
+#[test]
+fn test_as_client_consensus() {
+    let client_id = ClientId::new("07-tendermint", 0).unwrap();
+    let height = Height::new(1, 10).unwrap();
+
+    let path = Path::ClientConsensusState(ClientConsensusStatePath::from_height(
+        client_id.clone(),
+        height,
+    ));
+    assert_eq!(path.as_client_consensus(), Some((&client_id, height)));
+
+    let path = Path::ClientUpdateTime(ClientUpdateTimePath::from_height(client_id.clone(), height));
+    assert_eq!(path.as_client_consensus(), Some((&client_id, height)));
+
+    let path = Path::ClientUpdateHeight(ClientUpdateHeightPath::from_height(
+        client_id.clone(),
+        height,
+    ));
+    assert_eq!(path.as_client_consensus(), Some((&client_id, height)));
+
+    let path = Path::ClientConsensusState(ClientConsensusStatePath::new(client_id.clone(), 1, 0));
+    assert_eq!(path.as_client_consensus(), None);
+
+    let path: Path = "clients/07-tendermint-0/clientState".parse().unwrap();
+    assert_eq!(path.as_client_consensus(), None);
+}
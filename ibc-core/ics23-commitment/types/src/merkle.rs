@@ -13,7 +13,7 @@ use ibc_proto::ics23::{
     HostFunctionsProvider, NonExistenceProof,
 };
 
-use crate::commitment::CommitmentRoot;
+use crate::commitment::{CommitmentPrefix, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
 
@@ -37,6 +37,14 @@ impl MerklePath {
     pub fn new(key_path: Vec<PathBytes>) -> Self {
         Self { key_path }
     }
+
+    /// Constructs the ICS-23 `MerklePath` under which a key of the IBC store is
+    /// proven. It is ordered from the outermost store inwards: the commitment
+    /// prefix, i.e. the key of the IBC store within the multistore, followed by
+    /// the key of the value within the IBC store.
+    pub fn with_prefix(prefix: &CommitmentPrefix, path: PathBytes) -> Self {
+        Self::new(vec![prefix.as_bytes().to_vec().into(), path])
+    }
 }
 
 impl From<RawMerklePath> for MerklePath {
@@ -226,3 +234,39 @@ fn calculate_non_existence_root<H: HostFunctionsProvider>(
         Err(CommitmentError::InvalidMerkleProof)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+    use ibc_core_host_types::path::{CommitmentPath, Path};
+
+    use super::*;
+
+    #[test]
+    fn test_merkle_path_with_prefix() {
+        let path = Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+
+        let merkle_path =
+            MerklePath::with_prefix(&CommitmentPrefix::from_bytes("ibc"), path.clone().into());
+
+        assert_eq!(
+            merkle_path.key_path,
+            vec![
+                PathBytes::from_bytes("ibc"),
+                PathBytes::from_bytes("commitments/ports/transfer/channels/channel-0/sequences/1"),
+            ]
+        );
+        assert_eq!(
+            merkle_path
+                .key_path
+                .iter()
+                .map(|key| key.as_ref().to_vec())
+                .collect::<Vec<_>>(),
+            path.prefixed_key(b"ibc")
+        );
+    }
+}
@@ -23,8 +23,4 @@ pub mod proto {
     pub use ibc_proto::cosmos::upgrade::*;
 }
 
-/// ABCI store/query path for the IBC sub-store
-pub const IBC_QUERY_PATH: &str = "store/ibc/key";
-
-/// ABCI store/query path for the upgrade sub-store
-pub const SDK_UPGRADE_QUERY_PATH: &str = "store/upgrade/key";
+pub use ibc_core_host_types::path::{IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH};
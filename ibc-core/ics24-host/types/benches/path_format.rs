@@ -0,0 +1,67 @@
+use core::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use ibc_core_host_types::path::{CachedPath, Path};
+
+const PATHS: &[&str] = &[
+    "nextClientSequence",
+    "connections/connection-0",
+    "clients/07-tendermint-0/consensusStates/15-31",
+    "commitments/ports/transfer/channels/channel-0/sequences/42",
+];
+
+fn bench_path_to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_string");
+
+    for path in PATHS {
+        let path = Path::from_str(path).expect("valid path");
+
+        group.bench_function(path.to_string(), |b| {
+            b.iter_batched(
+                || path.clone(),
+                |path| black_box(path).to_string(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
+fn bench_path_into_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("into_string");
+
+    for path in PATHS {
+        let path = Path::from_str(path).expect("valid path");
+
+        group.bench_function(path.to_string(), |b| {
+            b.iter_batched(
+                || path.clone(),
+                |path| black_box(path).into_string(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
+fn bench_path_repeated_rendering(c: &mut Criterion) {
+    let mut group = c.benchmark_group("repeated_rendering");
+
+    for path in PATHS {
+        let path = Path::from_str(path).expect("valid path");
+        let cached = CachedPath::new(path.clone());
+
+        group.bench_function(format!("to_string/{path}"), |b| {
+            b.iter(|| black_box(&path).to_string())
+        });
+        group.bench_function(format!("cached_as_str/{path}"), |b| {
+            b.iter(|| black_box(&cached).as_str().len())
+        });
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_path_to_string,
+    bench_path_into_string,
+    bench_path_repeated_rendering
+);
+criterion_main!(benches);
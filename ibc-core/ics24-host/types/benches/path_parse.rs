@@ -0,0 +1,122 @@
+use core::cell::Cell;
+use core::str::FromStr;
+use std::alloc::{GlobalAlloc, Layout, System};
+
+use criterion::{black_box, criterion_group, Criterion, Throughput};
+use ibc_core_host_types::path::Path;
+
+/// Counts the allocations of the current thread, so that the allocations made
+/// while parsing a path can be checked.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// One representative path per variant, and each of the parameter keys.
+const PATHS: &[&str] = &[
+    "nextClientSequence",
+    "nextConnectionSequence",
+    "nextChannelSequence",
+    "clients/07-tendermint-0/clientState",
+    "clients/07-tendermint-0/clientType",
+    "clients/07-tendermint-0/consensusStates/15-31",
+    "clients/07-tendermint-0/consensusStates/15-31/processedTime",
+    "clients/07-tendermint-0/consensusStates/15-31/processedHeight",
+    "clients/07-tendermint-0/connections",
+    "connections/connection-0",
+    "ports/transfer",
+    "channelEnds/ports/transfer/channels/channel-0",
+    "nextSequenceSend/ports/transfer/channels/channel-0",
+    "nextSequenceRecv/ports/transfer/channels/channel-0",
+    "nextSequenceAck/ports/transfer/channels/channel-0",
+    "commitments/ports/transfer/channels/channel-0/sequences/42",
+    "acks/ports/transfer/channels/channel-0/sequences/42",
+    "receipts/ports/transfer/channels/channel-0/sequences/42",
+    "upgradedIBCState/42/upgradedClient",
+    "upgradedIBCState/42/upgradedConsState",
+    "interchainAccounts/connection-0/owner",
+    "capabilities/42",
+    "clientParams",
+    "connectionParams",
+    "channelParams",
+    "clients/07-tendermint-0/consensusStates",
+];
+
+fn bench_path_from_str(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str");
+    group.throughput(Throughput::Elements(1));
+
+    for path in PATHS {
+        group.bench_function(*path, |b| b.iter(|| Path::from_str(black_box(path))));
+    }
+}
+
+fn bench_path_from_str_mixed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_str_mixed");
+    group.throughput(Throughput::Elements(PATHS.len() as u64));
+
+    group.bench_function("all_variants", |b| {
+        b.iter(|| {
+            for path in PATHS {
+                let _ = black_box(Path::from_str(black_box(path)));
+            }
+        })
+    });
+}
+
+/// Paths along with the number of allocations parsing them takes, i.e. that
+/// of the identifiers they hold. The components of a path are split into a
+/// stack buffer, so that no other allocation is made.
+const ALLOCATIONS_PER_PATH: &[(&str, usize)] = &[
+    ("nextClientSequence", 0),
+    ("nextConnectionSequence", 0),
+    ("nextChannelSequence", 0),
+    ("clientParams", 0),
+    ("capabilities/42", 0),
+    ("connections/connection-0", 1),
+    ("ports/transfer", 1),
+];
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    drop(result);
+
+    after - before
+}
+
+fn check_path_from_str_allocations() {
+    for (path, expected) in ALLOCATIONS_PER_PATH {
+        let allocations =
+            count_allocations(|| Path::from_str(black_box(path)).expect("valid path"));
+
+        assert_eq!(allocations, *expected, "allocations parsing {path}");
+    }
+}
+
+criterion_group!(benches, bench_path_from_str, bench_path_from_str_mixed);
+
+fn main() {
+    check_path_from_str_allocations();
+
+    benches();
+
+    Criterion::default().configure_from_args().final_summary();
+}
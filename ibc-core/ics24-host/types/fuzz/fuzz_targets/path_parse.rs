@@ -0,0 +1,18 @@
+#![no_main]
+
+use core::str::FromStr;
+
+use ibc_core_host_types::path::Path;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = core::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(path) = Path::from_str(s) {
+        let rendered = path.to_string();
+
+        assert_eq!(Path::from_str(&rendered).ok(), Some(path), "{s:?}");
+    }
+});
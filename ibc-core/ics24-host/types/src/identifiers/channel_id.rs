@@ -24,7 +24,7 @@ const CHANNEL_ID_PREFIX: &str = "channel";
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct ChannelId(String);
+pub struct ChannelId(pub(super) String);
 
 impl ChannelId {
     /// Builds a new channel identifier. Like client and connection identifiers, channel ids are
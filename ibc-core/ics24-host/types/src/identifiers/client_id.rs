@@ -21,7 +21,7 @@ use crate::validate::{validate_client_identifier, validate_client_type};
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into, derive_more::Display)]
-pub struct ClientId(String);
+pub struct ClientId(pub(super) String);
 
 impl ClientId {
     /// Builds a new client identifier.
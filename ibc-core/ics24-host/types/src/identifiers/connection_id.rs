@@ -24,7 +24,7 @@ const CONNECTION_ID_PREFIX: &str = "connection";
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct ConnectionId(String);
+pub struct ConnectionId(pub(super) String);
 
 impl ConnectionId {
     /// Builds a new connection identifier. Connection identifiers are deterministically formed from
@@ -15,3 +15,25 @@ pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
 pub use port_id::PortId;
 pub use sequence::Sequence;
+
+use ibc_primitives::prelude::*;
+
+/// An identifier which the path parsers wrap as is when its validation is
+/// relaxed by an [`IdValidator`](crate::path::IdValidator).
+pub(crate) trait UncheckedIdentifier {
+    fn new_unchecked(id: &str) -> Self;
+}
+
+macro_rules! impl_unchecked_identifier {
+    ($($id:ident),* $(,)?) => {
+        $(
+            impl UncheckedIdentifier for $id {
+                fn new_unchecked(id: &str) -> Self {
+                    Self(id.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_unchecked_identifier!(ClientId, ConnectionId, PortId, ChannelId);
@@ -24,7 +24,7 @@ const TRANSFER_PORT_ID: &str = "transfer";
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
-pub struct PortId(String);
+pub struct PortId(pub(super) String);
 
 impl PortId {
     pub fn new(id: String) -> Result<Self, IdentifierError> {
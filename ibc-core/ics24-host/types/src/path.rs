@@ -4,12 +4,23 @@
 /// https://github.com/cosmos/ibc/tree/master/spec/core/ics-024-host-requirements#path-space
 /// Some of these are implemented in other ICSs, but ICS-024 has a nice summary table.
 ///
-use core::str::FromStr;
+use alloc::borrow::Cow;
+use core::cell::OnceCell;
+use core::num::{IntErrorKind, ParseIntError};
+use core::ops::{Deref, Range, RangeInclusive};
+use core::str::{FromStr, Utf8Error};
 
 use derive_more::{Display, From};
 use ibc_primitives::prelude::*;
 
-use crate::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use crate::error::IdentifierError;
+use crate::identifiers::{
+    ChannelId, ClientId, ConnectionId, PortId, Sequence, UncheckedIdentifier,
+};
+use crate::validate::{
+    validate_channel_identifier, validate_client_identifier, validate_connection_identifier,
+    validate_port_identifier,
+};
 
 pub const NEXT_CLIENT_SEQUENCE: &str = "nextClientSequence";
 pub const NEXT_CONNECTION_SEQUENCE: &str = "nextConnectionSequence";
@@ -17,6 +28,7 @@ pub const NEXT_CHANNEL_SEQUENCE: &str = "nextChannelSequence";
 
 pub const CLIENT_PREFIX: &str = "clients";
 pub const CLIENT_STATE: &str = "clientState";
+pub const CLIENT_TYPE: &str = "clientType";
 pub const CONSENSUS_STATE_PREFIX: &str = "consensusStates";
 pub const CONNECTION_PREFIX: &str = "connections";
 pub const CHANNEL_PREFIX: &str = "channels";
@@ -42,6 +54,65 @@ pub const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
 /// - The key identifying the upgraded consensus state
 pub const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
 
+/// ICS-27 interchain accounts keys
+/// - The prefix under which the accounts of each connection are stored
+pub const INTERCHAIN_ACCOUNT_PREFIX: &str = "interchainAccounts";
+
+/// ICS-05 capability keys
+/// - The prefix under which capabilities are stored by their index
+pub const CAPABILITY_PREFIX: &str = "capabilities";
+
+/// Module parameters keys, as stored by ibc-go
+/// - The key of the ICS-02 client parameters
+pub const CLIENT_PARAMS: &str = "clientParams";
+/// - The key of the ICS-03 connection parameters
+pub const CONNECTION_PARAMS: &str = "connectionParams";
+/// - The key of the ICS-04 channel parameters
+pub const CHANNEL_PARAMS: &str = "channelParams";
+
+/// ABCI store/query path for the IBC sub-store
+pub const IBC_QUERY_PATH: &str = "store/ibc/key";
+
+/// ABCI store/query path for the upgrade sub-store
+pub const SDK_UPGRADE_QUERY_PATH: &str = "store/upgrade/key";
+
+/// The maximum number of `/`-separated components of any valid path, i.e.
+/// those of the packet commitment, ack and receipt paths.
+const MAX_PATH_COMPONENTS: usize = 7;
+
+/// The constant components of paths, which [`Path::from_str_lenient`] matches
+/// regardless of their case.
+const KEYWORDS: &[&str] = &[
+    NEXT_CLIENT_SEQUENCE,
+    NEXT_CONNECTION_SEQUENCE,
+    NEXT_CHANNEL_SEQUENCE,
+    CLIENT_PREFIX,
+    CLIENT_STATE,
+    CLIENT_TYPE,
+    CONSENSUS_STATE_PREFIX,
+    CONNECTION_PREFIX,
+    CHANNEL_PREFIX,
+    CHANNEL_END_PREFIX,
+    PORT_PREFIX,
+    SEQUENCE_PREFIX,
+    NEXT_SEQ_SEND_PREFIX,
+    NEXT_SEQ_RECV_PREFIX,
+    NEXT_SEQ_ACK_PREFIX,
+    PACKET_COMMITMENT_PREFIX,
+    PACKET_ACK_PREFIX,
+    PACKET_RECEIPT_PREFIX,
+    PROCESSED_TIME,
+    PROCESSED_HEIGHT,
+    UPGRADED_IBC_STATE,
+    UPGRADED_CLIENT_STATE,
+    UPGRADED_CLIENT_CONSENSUS_STATE,
+    INTERCHAIN_ACCOUNT_PREFIX,
+    CAPABILITY_PREFIX,
+    CLIENT_PARAMS,
+    CONNECTION_PARAMS,
+    CHANNEL_PARAMS,
+];
+
 /// Represents a general-purpose path structure using the byte representation of
 /// a path. This struct abstracts over different path types and can handle bytes
 /// obtained from various serialization formats (e.g., Protobuf, Borsh).
@@ -79,7 +150,44 @@ impl AsRef<[u8]> for PathBytes {
     }
 }
 
+impl From<Path> for PathBytes {
+    fn from(path: Path) -> Self {
+        Self(path.into_bytes())
+    }
+}
+
 /// The Path enum abstracts out the different sub-paths.
+///
+/// Note that the derived [`Ord`] follows the order of the variants and their
+/// fields, not the byte order of the rendered keys in a store. Use
+/// [`Path::store_key_cmp`] for the latter.
+///
+/// Likewise, the derived [`Hash`] depends on the layout of the enum and may
+/// change across versions of this crate. Use [`Path::stable_hash`] for hashes
+/// that are persisted.
+///
+/// A path parsed from a string is in canonical form: a leading `/` is
+/// dropped, and [`Path::from_str_lenient`] normalizes the case of the constant
+/// components. Identifiers are case-sensitive in ICS-24 and are kept as is, so
+/// that e.g. "07-Tendermint-0" and "07-tendermint-0" remain distinct clients.
+/// Equal parsed paths can thus be deduplicated by comparing them directly.
+/// This does not hold for paths built by hand or decoded with serde, borsh or
+/// SCALE, whose fields are not checked. Use [`Path::canonical`] to bring them
+/// into canonical form first.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
 pub enum Path {
     NextClientSequence(NextClientSequencePath),
@@ -101,6 +209,126 @@ pub enum Path {
     Receipt(ReceiptPath),
     UpgradeClientState(UpgradeClientStatePath),
     UpgradeConsensusState(UpgradeConsensusStatePath),
+    InterchainAccount(InterchainAccountPath),
+    Capability(CapabilityPath),
+    Params(ParamsPath),
+    ClientConsensusStatePrefix(ClientConsensusStatePrefixPath),
+    ClientType(ClientTypePath),
+}
+
+/// The kind of a [`Path`], i.e. its variant without the payload.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathKind {
+    NextClientSequence,
+    NextConnectionSequence,
+    NextChannelSequence,
+    ClientState,
+    ClientConsensusState,
+    ClientUpdateTime,
+    ClientUpdateHeight,
+    ClientConnection,
+    Connection,
+    Ports,
+    ChannelEnd,
+    SeqSend,
+    SeqRecv,
+    SeqAck,
+    Commitment,
+    Ack,
+    Receipt,
+    UpgradeClientState,
+    UpgradeConsensusState,
+    InterchainAccount,
+    Capability,
+    Params,
+    ClientConsensusStatePrefix,
+    ClientType,
+}
+
+impl PathKind {
+    /// Returns the name of the kind, i.e. of the [`Path`] variant.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PathKind::NextClientSequence => "NextClientSequence",
+            PathKind::NextConnectionSequence => "NextConnectionSequence",
+            PathKind::NextChannelSequence => "NextChannelSequence",
+            PathKind::ClientState => "ClientState",
+            PathKind::ClientConsensusState => "ClientConsensusState",
+            PathKind::ClientUpdateTime => "ClientUpdateTime",
+            PathKind::ClientUpdateHeight => "ClientUpdateHeight",
+            PathKind::ClientConnection => "ClientConnection",
+            PathKind::Connection => "Connection",
+            PathKind::Ports => "Ports",
+            PathKind::ChannelEnd => "ChannelEnd",
+            PathKind::SeqSend => "SeqSend",
+            PathKind::SeqRecv => "SeqRecv",
+            PathKind::SeqAck => "SeqAck",
+            PathKind::Commitment => "Commitment",
+            PathKind::Ack => "Ack",
+            PathKind::Receipt => "Receipt",
+            PathKind::UpgradeClientState => "UpgradeClientState",
+            PathKind::UpgradeConsensusState => "UpgradeConsensusState",
+            PathKind::InterchainAccount => "InterchainAccount",
+            PathKind::Capability => "Capability",
+            PathKind::Params => "Params",
+            PathKind::ClientConsensusStatePrefix => "ClientConsensusStatePrefix",
+            PathKind::ClientType => "ClientType",
+        }
+    }
+}
+
+/// The leading component of the paths of each [`PathKind`], as returned by
+/// [`Path::prefix`], e.g. for generating a router that dispatches on it.
+///
+/// The upgrade paths are listed with the prefix of the default upgrade path.
+/// [`PathKind::Params`] is listed once for each of the parameter keys, which
+/// make up the whole of these paths; any other kind is listed exactly once.
+pub const PATH_PREFIXES: &[(PathKind, &str)] = &[
+    (PathKind::NextClientSequence, NEXT_CLIENT_SEQUENCE),
+    (PathKind::NextConnectionSequence, NEXT_CONNECTION_SEQUENCE),
+    (PathKind::NextChannelSequence, NEXT_CHANNEL_SEQUENCE),
+    (PathKind::ClientState, CLIENT_PREFIX),
+    (PathKind::ClientConsensusState, CLIENT_PREFIX),
+    (PathKind::ClientUpdateTime, CLIENT_PREFIX),
+    (PathKind::ClientUpdateHeight, CLIENT_PREFIX),
+    (PathKind::ClientConnection, CLIENT_PREFIX),
+    (PathKind::Connection, CONNECTION_PREFIX),
+    (PathKind::Ports, PORT_PREFIX),
+    (PathKind::ChannelEnd, CHANNEL_END_PREFIX),
+    (PathKind::SeqSend, NEXT_SEQ_SEND_PREFIX),
+    (PathKind::SeqRecv, NEXT_SEQ_RECV_PREFIX),
+    (PathKind::SeqAck, NEXT_SEQ_ACK_PREFIX),
+    (PathKind::Commitment, PACKET_COMMITMENT_PREFIX),
+    (PathKind::Ack, PACKET_ACK_PREFIX),
+    (PathKind::Receipt, PACKET_RECEIPT_PREFIX),
+    (PathKind::UpgradeClientState, UPGRADED_IBC_STATE),
+    (PathKind::UpgradeConsensusState, UPGRADED_IBC_STATE),
+    (PathKind::InterchainAccount, INTERCHAIN_ACCOUNT_PREFIX),
+    (PathKind::Capability, CAPABILITY_PREFIX),
+    (PathKind::Params, CLIENT_PARAMS),
+    (PathKind::Params, CONNECTION_PARAMS),
+    (PathKind::Params, CHANNEL_PARAMS),
+    (PathKind::ClientConsensusStatePrefix, CLIENT_PREFIX),
+    (PathKind::ClientType, CLIENT_PREFIX),
+];
+
+/// The logical store a [`Path`] belongs to, as returned by [`Path::store`].
+/// This allows routing reads and writes to separate key-value stores.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StoreType {
+    /// Client states, types and consensus states, their processed times and
+    /// heights, and the connections of each client.
+    Client,
+    /// Connection ends and interchain accounts, which are keyed by connection.
+    Connection,
+    /// Ports, channel ends, packet commitments, acks and receipts, and
+    /// capabilities.
+    Channel,
+    /// Upgraded client and consensus states.
+    Upgrade,
+    /// The client, connection and channel sequence counters, and the
+    /// per-channel send, receive and ack sequence counters.
+    Sequence,
 }
 
 #[cfg_attr(
@@ -116,6 +344,7 @@ pub enum Path {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_CLIENT_SEQUENCE}")]
 pub struct NextClientSequencePath;
@@ -133,6 +362,7 @@ pub struct NextClientSequencePath;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_CONNECTION_SEQUENCE}")]
 pub struct NextConnectionSequencePath;
@@ -150,6 +380,7 @@ pub struct NextConnectionSequencePath;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_CHANNEL_SEQUENCE}")]
 pub struct NextChannelSequencePath;
@@ -167,6 +398,7 @@ pub struct NextChannelSequencePath;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display, From)]
 #[display("{CLIENT_PREFIX}/{_0}/{CLIENT_STATE}")]
 pub struct ClientStatePath(pub ClientId);
@@ -189,6 +421,43 @@ impl ClientStatePath {
     }
 }
 
+impl From<&ClientId> for ClientStatePath {
+    fn from(client_id: &ClientId) -> Self {
+        ClientStatePath(client_id.clone())
+    }
+}
+
+/// Some legacy stores keep the client type of each client under this key.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{CLIENT_PREFIX}/{_0}/{CLIENT_TYPE}")]
+pub struct ClientTypePath(pub ClientId);
+
+impl ClientTypePath {
+    pub fn new(client_id: ClientId) -> ClientTypePath {
+        ClientTypePath(client_id)
+    }
+}
+
+/// The consensus state of a client at the given height.
+///
+/// The revision number and height are kept as is, so that the paths of any
+/// stored height round-trip. In particular, the revision number is zero on
+/// chains which never upgraded, e.g. "0-100". Note that a zero revision height
+/// is not a valid `Height`, which callers converting to one must handle.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -202,6 +471,8 @@ impl ClientStatePath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{CLIENT_PREFIX}/{client_id}/{CONSENSUS_STATE_PREFIX}/{revision_number}-{revision_height}"
@@ -212,6 +483,33 @@ pub struct ClientConsensusStatePath {
     pub revision_height: u64,
 }
 
+/// The key prefix under which all the consensus states of a client are
+/// stored, i.e. the start key of a range scan over them, e.g. when pruning
+/// expired consensus states. No value is stored under this key itself.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{CLIENT_PREFIX}/{_0}/{CONSENSUS_STATE_PREFIX}")]
+pub struct ClientConsensusStatePrefixPath(pub ClientId);
+
+impl ClientConsensusStatePrefixPath {
+    pub fn new(client_id: ClientId) -> ClientConsensusStatePrefixPath {
+        ClientConsensusStatePrefixPath(client_id)
+    }
+}
+
 // Returns the full consensus state path of specific client in the format:
 // "clients/{client_id}/consensusStates" as a string.
 pub fn full_consensus_state_path(client_id: &ClientId) -> String {
@@ -232,6 +530,24 @@ impl ClientConsensusStatePath {
         }
     }
 
+    /// Constructs a new `ClientConsensusStatePath` from a borrowed client
+    /// identifier, cloning it.
+    pub fn new_ref(
+        client_id: &ClientId,
+        revision_number: u64,
+        revision_height: u64,
+    ) -> ClientConsensusStatePath {
+        Self::new(client_id.clone(), revision_number, revision_height)
+    }
+
+    /// Constructs a new `ClientConsensusStatePath` from a height in the
+    /// "{revision_number}-{revision_height}" form, as carried by ABCI events.
+    pub fn from_parts(client_id: ClientId, revision: &str) -> Result<Self, PathError> {
+        let (revision_number, revision_height) = parse_revision(revision)?;
+
+        Ok(Self::new(client_id, revision_number, revision_height))
+    }
+
     /// Returns the path representing the parent group under which all consensus
     /// states are stored: "clients/{client_id}/consensusStates".
     pub fn parent(&self) -> String {
@@ -262,6 +578,8 @@ impl ClientConsensusStatePath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{CLIENT_PREFIX}/{client_id}/{CONSENSUS_STATE_PREFIX}/{revision_number}-{revision_height}/{PROCESSED_TIME}"
@@ -312,6 +630,8 @@ impl ClientUpdateTimePath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{CLIENT_PREFIX}/{client_id}/{CONSENSUS_STATE_PREFIX}/{revision_number}-{revision_height}/{PROCESSED_HEIGHT}"
@@ -382,6 +702,7 @@ pub fn iteration_key(revision_number: u64, revision_height: u64) -> Vec<u8> {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{CLIENT_PREFIX}/{_0}/{CONNECTION_PREFIX}")]
 pub struct ClientConnectionPath(pub ClientId);
@@ -390,6 +711,67 @@ impl ClientConnectionPath {
     pub fn new(client_id: ClientId) -> ClientConnectionPath {
         ClientConnectionPath(client_id)
     }
+
+    /// Encodes the list of connection identifiers stored under the path. As
+    /// in ibc-go, the list is encoded as the `ClientPaths` protobuf message,
+    /// i.e. as a repeated string field with tag 1.
+    pub fn encode_connections(ids: &[ConnectionId]) -> Vec<u8> {
+        use prost::encoding::{encode_key, encode_varint, WireType};
+
+        let mut bytes = Vec::new();
+
+        for id in ids {
+            encode_key(1, WireType::LengthDelimited, &mut bytes);
+            encode_varint(id.as_str().len() as u64, &mut bytes);
+            bytes.extend_from_slice(id.as_str().as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes the list of connection identifiers stored under the path, i.e.
+    /// the inverse of [`ClientConnectionPath::encode_connections`]. Each
+    /// identifier is validated.
+    pub fn decode_connections(mut bytes: &[u8]) -> Result<Vec<ConnectionId>, PathError> {
+        use prost::encoding::{decode_key, decode_varint, WireType};
+
+        let mut ids = Vec::new();
+
+        while !bytes.is_empty() {
+            let (tag, wire_type) =
+                decode_key(&mut bytes).map_err(|e| PathError::InvalidClientConnections {
+                    reason: e.to_string(),
+                })?;
+
+            if tag != 1 || wire_type != WireType::LengthDelimited {
+                return Err(PathError::InvalidClientConnections {
+                    reason: format!("unexpected field {tag} of type {wire_type:?}"),
+                });
+            }
+
+            let len =
+                decode_varint(&mut bytes).map_err(|e| PathError::InvalidClientConnections {
+                    reason: e.to_string(),
+                })?;
+
+            let len = usize::try_from(len)
+                .ok()
+                .filter(|len| *len <= bytes.len())
+                .ok_or_else(|| PathError::InvalidClientConnections {
+                    reason: format!("length {len} exceeds the remaining bytes"),
+                })?;
+
+            let (id, rest) = bytes.split_at(len);
+
+            ids.push(parse_identifier(
+                core::str::from_utf8(id).map_err(PathError::InvalidUtf8)?,
+            )?);
+
+            bytes = rest;
+        }
+
+        Ok(ids)
+    }
 }
 
 #[cfg_attr(
@@ -405,6 +787,7 @@ impl ClientConnectionPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{CONNECTION_PREFIX}/{_0}")]
 pub struct ConnectionPath(pub ConnectionId);
@@ -414,6 +797,12 @@ impl ConnectionPath {
         ConnectionPath(connection_id.clone())
     }
 
+    /// Returns the path of the connection with the given counter, i.e. of
+    /// "connection-{counter}".
+    pub fn from_seq(counter: u64) -> ConnectionPath {
+        ConnectionPath(ConnectionId::new(counter))
+    }
+
     /// Returns the connection store prefix under which all the connections are
     /// stored: "connections".
     pub fn prefix() -> String {
@@ -421,6 +810,12 @@ impl ConnectionPath {
     }
 }
 
+impl From<&ConnectionId> for ConnectionPath {
+    fn from(connection_id: &ConnectionId) -> Self {
+        ConnectionPath::new(connection_id)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -434,10 +829,23 @@ impl ConnectionPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{PORT_PREFIX}/{_0}")]
 pub struct PortPath(pub PortId);
 
+impl PortPath {
+    pub fn new(port_id: &PortId) -> PortPath {
+        PortPath(port_id.clone())
+    }
+}
+
+impl From<&PortId> for PortPath {
+    fn from(port_id: &PortId) -> Self {
+        PortPath::new(port_id)
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -451,6 +859,7 @@ pub struct PortPath(pub PortId);
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{CHANNEL_END_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct ChannelEndPath(pub PortId, pub ChannelId);
@@ -460,6 +869,20 @@ impl ChannelEndPath {
         ChannelEndPath(port_id.clone(), channel_id.clone())
     }
 
+    /// Returns the path of the channel end with the given counter, i.e. of
+    /// "channel-{counter}", on the given port.
+    pub fn from_seqs(port_id: &PortId, counter: u64) -> ChannelEndPath {
+        ChannelEndPath(port_id.clone(), ChannelId::new(counter))
+    }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.0
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.1
+    }
+
     /// Returns the channel end store prefix under which all the channel ends
     /// are stored: "channelEnds".
     pub fn prefix() -> String {
@@ -508,6 +931,7 @@ impl ChannelEndPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_SEQ_SEND_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqSendPath(pub PortId, pub ChannelId);
@@ -516,8 +940,20 @@ impl SeqSendPath {
     pub fn new(port_id: &PortId, channel_id: &ChannelId) -> SeqSendPath {
         SeqSendPath(port_id.clone(), channel_id.clone())
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.0
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.1
+    }
 }
 
+/// The next sequence to be received on a channel. As per ICS-04, the key is
+/// the same for ORDERED and UNORDERED channels: ordered channels enforce the
+/// sequence, while unordered channels record each received packet under a
+/// [`ReceiptPath`] instead, and only keep this counter for upgrades.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -531,6 +967,7 @@ impl SeqSendPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_SEQ_RECV_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqRecvPath(pub PortId, pub ChannelId);
@@ -539,6 +976,14 @@ impl SeqRecvPath {
     pub fn new(port_id: &PortId, channel_id: &ChannelId) -> SeqRecvPath {
         SeqRecvPath(port_id.clone(), channel_id.clone())
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.0
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.1
+    }
 }
 
 #[cfg_attr(
@@ -554,6 +999,7 @@ impl SeqRecvPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{NEXT_SEQ_ACK_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}")]
 pub struct SeqAckPath(pub PortId, pub ChannelId);
@@ -562,6 +1008,38 @@ impl SeqAckPath {
     pub fn new(port_id: &PortId, channel_id: &ChannelId) -> SeqAckPath {
         SeqAckPath(port_id.clone(), channel_id.clone())
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.0
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.1
+    }
+}
+
+/// Returns the per-channel keys which the packet handlers of an ORDERED
+/// channel rely on: the channel end and the send, receive and ack sequence
+/// counters, the latter two of which enforce the order of the packets.
+pub fn ordered_channel_paths(port_id: &PortId, channel_id: &ChannelId) -> Vec<Path> {
+    vec![
+        ChannelEndPath::new(port_id, channel_id).into(),
+        SeqSendPath::new(port_id, channel_id).into(),
+        SeqRecvPath::new(port_id, channel_id).into(),
+        SeqAckPath::new(port_id, channel_id).into(),
+    ]
+}
+
+/// Returns the per-channel keys which the packet handlers of an UNORDERED
+/// channel rely on: the channel end and the send sequence counter. Received
+/// packets are instead tracked by a [`ReceiptPath`] per sequence, stored under
+/// [`ChannelEndPath::receipts_path`], see [`ReceiptPath::range`]. The receive
+/// and ack sequence counters are not enforced, and are thus left out.
+pub fn unordered_channel_paths(port_id: &PortId, channel_id: &ChannelId) -> Vec<Path> {
+    vec![
+        ChannelEndPath::new(port_id, channel_id).into(),
+        SeqSendPath::new(port_id, channel_id).into(),
+    ]
 }
 
 #[cfg_attr(
@@ -577,6 +1055,8 @@ impl SeqAckPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{PACKET_COMMITMENT_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
@@ -596,6 +1076,18 @@ impl CommitmentPath {
         }
     }
 
+    /// Returns the packet commitment paths of a channel for the given range of
+    /// sequences.
+    pub fn range(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: RangeInclusive<u64>,
+    ) -> impl Iterator<Item = CommitmentPath> {
+        let (port_id, channel_id) = (port_id.clone(), channel_id.clone());
+
+        sequences.map(move |sequence| CommitmentPath::new(&port_id, &channel_id, sequence.into()))
+    }
+
     /// Returns the commitment store prefix under which all the packet
     /// commitments are stored: "commitments"
     pub fn prefix() -> String {
@@ -616,6 +1108,8 @@ impl CommitmentPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{PACKET_ACK_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
@@ -635,6 +1129,18 @@ impl AckPath {
         }
     }
 
+    /// Returns the packet ack paths of a channel for the given range of
+    /// sequences.
+    pub fn range(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: RangeInclusive<u64>,
+    ) -> impl Iterator<Item = AckPath> {
+        let (port_id, channel_id) = (port_id.clone(), channel_id.clone());
+
+        sequences.map(move |sequence| AckPath::new(&port_id, &channel_id, sequence.into()))
+    }
+
     /// Returns the ack store prefix under which all the packet acks are stored:
     /// "acks"
     pub fn prefix() -> String {
@@ -655,6 +1161,8 @@ impl AckPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(
     "{PACKET_RECEIPT_PREFIX}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}/{sequence}"
@@ -674,6 +1182,37 @@ impl ReceiptPath {
         }
     }
 
+    /// Returns the packet receipt paths of a channel for the given range of
+    /// sequences.
+    pub fn range(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: RangeInclusive<u64>,
+    ) -> impl Iterator<Item = ReceiptPath> {
+        let (port_id, channel_id) = (port_id.clone(), channel_id.clone());
+
+        sequences.map(move |sequence| ReceiptPath::new(&port_id, &channel_id, sequence.into()))
+    }
+
+    /// Returns the packet receipt paths of a channel for the given sequences,
+    /// e.g. those expanded from a compact set of received packets.
+    pub fn from_sequences(
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequences: &[Sequence],
+    ) -> Vec<ReceiptPath> {
+        sequences
+            .iter()
+            .map(|sequence| ReceiptPath::new(port_id, channel_id, *sequence))
+            .collect()
+    }
+
+    /// Returns the sequences of the given packet receipt paths, i.e. the
+    /// inverse of [`ReceiptPath::from_sequences`].
+    pub fn collect_sequences(paths: &[ReceiptPath]) -> Vec<Sequence> {
+        paths.iter().map(|path| path.sequence).collect()
+    }
+
     /// Returns the receipt store prefix under which all the packet receipts are
     /// stored: "receipts"
     pub fn prefix() -> String {
@@ -681,6 +1220,46 @@ impl ReceiptPath {
     }
 }
 
+/// Identifies a packet by its port, channel and sequence, regardless of
+/// whether it is found through its commitment, ack or receipt path. This
+/// allows correlating the keys of a single packet, e.g. in a packet queue.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketKey {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl From<&CommitmentPath> for PacketKey {
+    fn from(path: &CommitmentPath) -> Self {
+        PacketKey {
+            port_id: path.port_id.clone(),
+            channel_id: path.channel_id.clone(),
+            sequence: path.sequence,
+        }
+    }
+}
+
+impl From<&AckPath> for PacketKey {
+    fn from(path: &AckPath) -> Self {
+        PacketKey {
+            port_id: path.port_id.clone(),
+            channel_id: path.channel_id.clone(),
+            sequence: path.sequence,
+        }
+    }
+}
+
+impl From<&ReceiptPath> for PacketKey {
+    fn from(path: &ReceiptPath) -> Self {
+        PacketKey {
+            port_id: path.port_id.clone(),
+            channel_id: path.channel_id.clone(),
+            sequence: path.sequence,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -694,6 +1273,8 @@ impl ReceiptPath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{upgrade_path}/{height}/{UPGRADED_CLIENT_STATE}")]
 pub struct UpgradeClientStatePath {
@@ -702,13 +1283,20 @@ pub struct UpgradeClientStatePath {
 }
 
 impl UpgradeClientStatePath {
-    /// Create with the default upgrade path
-    pub fn new_with_default_path(height: u64) -> Self {
+    /// Create with the given upgrade path, e.g. "upgrade/upgradedIBCState".
+    /// For the path to be parsed back, its leading component must not be the
+    /// prefix of any other kind of path.
+    pub fn new(upgrade_path: String, height: u64) -> Self {
         Self {
-            upgrade_path: UPGRADED_IBC_STATE.to_string(),
+            upgrade_path,
             height,
         }
     }
+
+    /// Create with the default upgrade path
+    pub fn new_with_default_path(height: u64) -> Self {
+        Self::new(UPGRADED_IBC_STATE.to_string(), height)
+    }
 }
 
 #[cfg_attr(
@@ -724,6 +1312,8 @@ impl UpgradeClientStatePath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display("{upgrade_path}/{height}/{UPGRADED_CLIENT_CONSENSUS_STATE}")]
 pub struct UpgradeConsensusStatePath {
@@ -732,15 +1322,24 @@ pub struct UpgradeConsensusStatePath {
 }
 
 impl UpgradeConsensusStatePath {
-    /// Create with the default upgrade path
-    pub fn new_with_default_path(height: u64) -> Self {
+    /// Create with the given upgrade path, e.g. "upgrade/upgradedIBCState".
+    /// For the path to be parsed back, its leading component must not be the
+    /// prefix of any other kind of path.
+    pub fn new(upgrade_path: String, height: u64) -> Self {
         Self {
-            upgrade_path: UPGRADED_IBC_STATE.to_string(),
+            upgrade_path,
             height,
         }
     }
+
+    /// Create with the default upgrade path
+    pub fn new_with_default_path(height: u64) -> Self {
+        Self::new(UPGRADED_IBC_STATE.to_string(), height)
+    }
 }
 
+/// Maps an ICS-27 account owner on a connection to its interchain account.
+/// The owner is opaque to the host, but must be a single path component.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -754,540 +1353,4723 @@ impl UpgradeConsensusStatePath {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-/// Sub-paths which are not part of the specification, but are still
-/// useful to represent for parsing purposes.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-enum SubPath {
-    Channels(ChannelId),
-    Sequences(Sequence),
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{INTERCHAIN_ACCOUNT_PREFIX}/{connection_id}/{owner}")]
+pub struct InterchainAccountPath {
+    pub connection_id: ConnectionId,
+    pub owner: String,
 }
 
-impl Path {
-    /// Indication if the path is provable.
-    pub fn is_provable(&self) -> bool {
-        !matches!(&self, Path::ClientConnection(_) | Path::Ports(_))
-    }
-
-    /// into_bytes implementation
-    pub fn into_bytes(self) -> Vec<u8> {
-        self.to_string().into_bytes()
+impl InterchainAccountPath {
+    pub fn new(connection_id: ConnectionId, owner: String) -> InterchainAccountPath {
+        InterchainAccountPath {
+            connection_id,
+            owner,
+        }
     }
 }
 
-#[derive(Debug, displaydoc::Display)]
-pub enum PathError {
-    /// `{path}` could not be parsed into a Path
-    ParseFailure { path: String },
-}
-
-#[cfg(feature = "std")]
-impl std::error::Error for PathError {}
-
-/// The FromStr trait allows paths encoded as strings to be parsed into Paths.
-impl FromStr for Path {
-    type Err = PathError;
+/// Identifies an ICS-05 object capability, e.g. the one owning a port or a
+/// channel, by its index.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display("{CAPABILITY_PREFIX}/{_0}")]
+pub struct CapabilityPath(pub u64);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let components: Vec<&str> = s.split('/').collect();
-
-        parse_next_sequence(&components)
-            .or_else(|| parse_client_paths(&components))
-            .or_else(|| parse_connections(&components))
-            .or_else(|| parse_ports(&components))
-            .or_else(|| parse_channel_ends(&components))
-            .or_else(|| parse_seqs(&components))
-            .or_else(|| parse_commitments(&components))
-            .or_else(|| parse_acks(&components))
-            .or_else(|| parse_receipts(&components))
-            .or_else(|| parse_upgrade_client_state(&components))
-            .or_else(|| parse_upgrade_consensus_state(&components))
-            .ok_or(PathError::ParseFailure {
-                path: s.to_string(),
-            })
+impl CapabilityPath {
+    pub fn new(index: u64) -> CapabilityPath {
+        CapabilityPath(index)
     }
 }
 
-fn parse_next_sequence(components: &[&str]) -> Option<Path> {
-    if components.len() != 1 {
-        return None;
-    }
+/// Identifies the parameters of one of the core modules. Each of them is
+/// stored under a single key, e.g. "clientParams". Note that the parameters of
+/// applications, commonly stored under "params", are not part of the host
+/// store and hence not represented.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+pub enum ParamsPath {
+    /// The client parameters, stored under [`CLIENT_PARAMS`].
+    #[display("{CLIENT_PARAMS}")]
+    Client,
+    /// The connection parameters, stored under [`CONNECTION_PARAMS`].
+    #[display("{CONNECTION_PARAMS}")]
+    Connection,
+    /// The channel parameters, stored under [`CHANNEL_PARAMS`].
+    #[display("{CHANNEL_PARAMS}")]
+    Channel,
+}
 
-    match *components.first()? {
-        NEXT_CLIENT_SEQUENCE => Some(NextClientSequencePath.into()),
-        NEXT_CONNECTION_SEQUENCE => Some(NextConnectionSequencePath.into()),
-        NEXT_CHANNEL_SEQUENCE => Some(NextChannelSequencePath.into()),
-        _ => None,
+impl ParamsPath {
+    /// Returns the key the parameters are stored under.
+    pub fn key(&self) -> &'static str {
+        match self {
+            ParamsPath::Client => CLIENT_PARAMS,
+            ParamsPath::Connection => CONNECTION_PARAMS,
+            ParamsPath::Channel => CHANNEL_PARAMS,
+        }
     }
 }
 
-fn parse_client_paths(components: &[&str]) -> Option<Path> {
-    let first = *components.first()?;
-
-    if first != CLIENT_PREFIX {
-        return None;
-    }
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Sub-paths which are not part of the specification, but are still
+/// useful to represent for parsing purposes.
+///
+/// These are the building blocks for path fragments, which downstream parsers
+/// of custom keys may reuse through [`parse_channels`] and [`parse_sequences`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubPath {
+    Channels(ChannelId),
+    Sequences(Sequence),
+}
 
-    let client_id = ClientId::from_str(components[1]).ok()?;
+impl Path {
+    /// Returns the kind of the path.
+    pub fn kind(&self) -> PathKind {
+        match self {
+            Path::NextClientSequence(_) => PathKind::NextClientSequence,
+            Path::NextConnectionSequence(_) => PathKind::NextConnectionSequence,
+            Path::NextChannelSequence(_) => PathKind::NextChannelSequence,
+            Path::ClientState(_) => PathKind::ClientState,
+            Path::ClientType(_) => PathKind::ClientType,
+            Path::ClientConsensusState(_) => PathKind::ClientConsensusState,
+            Path::ClientUpdateTime(_) => PathKind::ClientUpdateTime,
+            Path::ClientUpdateHeight(_) => PathKind::ClientUpdateHeight,
+            Path::ClientConnection(_) => PathKind::ClientConnection,
+            Path::Connection(_) => PathKind::Connection,
+            Path::Ports(_) => PathKind::Ports,
+            Path::ChannelEnd(_) => PathKind::ChannelEnd,
+            Path::SeqSend(_) => PathKind::SeqSend,
+            Path::SeqRecv(_) => PathKind::SeqRecv,
+            Path::SeqAck(_) => PathKind::SeqAck,
+            Path::Commitment(_) => PathKind::Commitment,
+            Path::Ack(_) => PathKind::Ack,
+            Path::Receipt(_) => PathKind::Receipt,
+            Path::UpgradeClientState(_) => PathKind::UpgradeClientState,
+            Path::UpgradeConsensusState(_) => PathKind::UpgradeConsensusState,
+            Path::InterchainAccount(_) => PathKind::InterchainAccount,
+            Path::Capability(_) => PathKind::Capability,
+            Path::Params(_) => PathKind::Params,
+            Path::ClientConsensusStatePrefix(_) => PathKind::ClientConsensusStatePrefix,
+        }
+    }
 
-    if components.len() == 3 {
-        match components[2] {
-            CLIENT_STATE => Some(ClientStatePath(client_id).into()),
-            CONNECTION_PREFIX => Some(ClientConnectionPath(client_id).into()),
+    /// Returns the name of the variant of the path, e.g. "ClientState", for
+    /// logs in which the key alone may be ambiguous.
+    pub fn debug_label(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Returns the variant name of the path along with its key, e.g.
+    /// "ClientState(clients/07-tendermint-0/clientState)".
+    pub fn describe(&self) -> String {
+        format!("{}({self})", self.debug_label())
+    }
+
+    /// Returns the leading component of the path, which identifies the store
+    /// it belongs to, e.g. "clients" or "commitments". The sequence counters
+    /// consist of a single component, which is returned as is.
+    ///
+    /// Note that the prefix of an upgrade path is the leading component of its
+    /// configurable upgrade path, hence it is not necessarily `'static`.
+    pub fn prefix(&self) -> &str {
+        match self {
+            Path::NextClientSequence(_) => NEXT_CLIENT_SEQUENCE,
+            Path::NextConnectionSequence(_) => NEXT_CONNECTION_SEQUENCE,
+            Path::NextChannelSequence(_) => NEXT_CHANNEL_SEQUENCE,
+            Path::ClientState(_)
+            | Path::ClientType(_)
+            | Path::ClientConsensusState(_)
+            | Path::ClientUpdateTime(_)
+            | Path::ClientUpdateHeight(_)
+            | Path::ClientConnection(_)
+            | Path::ClientConsensusStatePrefix(_) => CLIENT_PREFIX,
+            Path::Connection(_) => CONNECTION_PREFIX,
+            Path::Ports(_) => PORT_PREFIX,
+            Path::ChannelEnd(_) => CHANNEL_END_PREFIX,
+            Path::SeqSend(_) => NEXT_SEQ_SEND_PREFIX,
+            Path::SeqRecv(_) => NEXT_SEQ_RECV_PREFIX,
+            Path::SeqAck(_) => NEXT_SEQ_ACK_PREFIX,
+            Path::Commitment(_) => PACKET_COMMITMENT_PREFIX,
+            Path::Ack(_) => PACKET_ACK_PREFIX,
+            Path::Receipt(_) => PACKET_RECEIPT_PREFIX,
+            Path::UpgradeClientState(UpgradeClientStatePath { upgrade_path, .. })
+            | Path::UpgradeConsensusState(UpgradeConsensusStatePath { upgrade_path, .. }) => {
+                upgrade_path
+                    .split_once('/')
+                    .map_or(upgrade_path, |(prefix, _)| prefix)
+            }
+            Path::InterchainAccount(_) => INTERCHAIN_ACCOUNT_PREFIX,
+            Path::Capability(_) => CAPABILITY_PREFIX,
+            Path::Params(path) => path.key(),
+        }
+    }
+
+    /// Indication if the leading component of the path, as returned by
+    /// [`Path::prefix`], equals the given prefix. This allows routing paths to
+    /// stores by prefix, without matching on every variant.
+    pub fn matches_prefix(&self, prefix: &str) -> bool {
+        self.prefix() == prefix
+    }
+
+    /// Indication if the key of the path lies under the given key prefix, e.g.
+    /// "clients/07-tendermint-0", for range scans over a subtree of the store.
+    ///
+    /// The prefix must end at a component boundary, so that
+    /// "clients/07-tendermint-0" does not cover the paths of the
+    /// "07-tendermint-01" client. A trailing `/` in the prefix is ignored, and
+    /// an empty prefix covers every path.
+    pub fn is_under(&self, prefix: &str) -> bool {
+        let prefix = prefix.trim_end_matches('/');
+
+        if prefix.is_empty() {
+            return true;
+        }
+
+        self.to_string()
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// Indication if the key of the path matches the given `/`-separated
+    /// pattern, e.g. "clients/*/clientState", component by component.
+    ///
+    /// A `*` component matches exactly one component of the key, whichever it
+    /// is, but never a `/`, so that the pattern and the key must consist of the
+    /// same number of components. Any other component must match verbatim;
+    /// `*` within a component, e.g. "07-tendermint-*", is not a wildcard.
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        let mut components = self.components();
+        let mut patterns = pattern.split('/');
+
+        loop {
+            match (components.next(), patterns.next()) {
+                (Some(component), Some(pattern)) => {
+                    if pattern != "*" && pattern != component {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns the key of the path without its last component, e.g.
+    /// "clients/07-tendermint-0/consensusStates" for a consensus state path,
+    /// or `None` for the single-component paths.
+    ///
+    /// Note that the parent key is generally not the key of a valid path.
+    pub fn parent_key(&self) -> Option<String> {
+        let mut key = self.to_string();
+
+        let len = key.rfind('/')?;
+        key.truncate(len);
+
+        Some(key)
+    }
+
+    /// Indication if the path is provable.
+    pub fn is_provable(&self) -> bool {
+        // Every variant is listed explicitly, so that adding a new one
+        // requires deciding on its provability.
+        match self {
+            Path::ClientConnection(_)
+            | Path::Ports(_)
+            | Path::InterchainAccount(_)
+            | Path::Capability(_)
+            | Path::ClientConsensusStatePrefix(_) => false,
+            Path::NextClientSequence(_)
+            | Path::NextConnectionSequence(_)
+            | Path::NextChannelSequence(_)
+            | Path::ClientState(_)
+            | Path::ClientType(_)
+            | Path::ClientConsensusState(_)
+            | Path::ClientUpdateTime(_)
+            | Path::ClientUpdateHeight(_)
+            | Path::Connection(_)
+            | Path::ChannelEnd(_)
+            | Path::SeqSend(_)
+            | Path::SeqRecv(_)
+            | Path::SeqAck(_)
+            | Path::Commitment(_)
+            | Path::Ack(_)
+            | Path::Receipt(_)
+            | Path::UpgradeClientState(_)
+            | Path::UpgradeConsensusState(_)
+            | Path::Params(_) => true,
+        }
+    }
+
+    /// Returns the logical store the path belongs to. See [`StoreType`] for the
+    /// mapping of each variant.
+    ///
+    /// Note that, unlike [`Path::is_client_path`] and its siblings, all the
+    /// sequence counters are mapped to [`StoreType::Sequence`].
+    pub fn store(&self) -> StoreType {
+        match self {
+            Path::ClientState(_)
+            | Path::ClientType(_)
+            | Path::ClientConsensusState(_)
+            | Path::ClientUpdateTime(_)
+            | Path::ClientUpdateHeight(_)
+            | Path::ClientConnection(_)
+            | Path::ClientConsensusStatePrefix(_)
+            | Path::Params(ParamsPath::Client) => StoreType::Client,
+            Path::Connection(_)
+            | Path::InterchainAccount(_)
+            | Path::Params(ParamsPath::Connection) => StoreType::Connection,
+            Path::Ports(_)
+            | Path::ChannelEnd(_)
+            | Path::Commitment(_)
+            | Path::Ack(_)
+            | Path::Receipt(_)
+            | Path::Capability(_)
+            | Path::Params(ParamsPath::Channel) => StoreType::Channel,
+            Path::UpgradeClientState(_) | Path::UpgradeConsensusState(_) => StoreType::Upgrade,
+            Path::NextClientSequence(_)
+            | Path::NextConnectionSequence(_)
+            | Path::NextChannelSequence(_)
+            | Path::SeqSend(_)
+            | Path::SeqRecv(_)
+            | Path::SeqAck(_) => StoreType::Sequence,
+        }
+    }
+
+    /// Indication if the path belongs to the client sub-store, i.e. it is
+    /// either the client sequence counter or is stored under the "clients"
+    /// prefix.
+    pub fn is_client_path(&self) -> bool {
+        matches!(
+            self,
+            Path::NextClientSequence(_)
+                | Path::ClientState(_)
+                | Path::ClientType(_)
+                | Path::ClientConsensusState(_)
+                | Path::ClientUpdateTime(_)
+                | Path::ClientUpdateHeight(_)
+                | Path::ClientConnection(_)
+                | Path::ClientConsensusStatePrefix(_)
+        )
+    }
+
+    /// Indication if the path belongs to the connection sub-store, i.e. it is
+    /// either the connection sequence counter or a connection end. Note that
+    /// the connections of a client are stored in the client sub-store.
+    pub fn is_connection_path(&self) -> bool {
+        matches!(self, Path::NextConnectionSequence(_) | Path::Connection(_))
+    }
+
+    /// Indication if the path belongs to the channel sub-store, i.e. it is
+    /// either the channel sequence counter, a channel end, one of the
+    /// per-channel sequence counters, or a packet commitment, ack or receipt.
+    /// Note that port paths are not channel-scoped and hence not included.
+    pub fn is_channel_path(&self) -> bool {
+        matches!(
+            self,
+            Path::NextChannelSequence(_)
+                | Path::ChannelEnd(_)
+                | Path::SeqSend(_)
+                | Path::SeqRecv(_)
+                | Path::SeqAck(_)
+                | Path::Commitment(_)
+                | Path::Ack(_)
+                | Path::Receipt(_)
+        )
+    }
+
+    /// Indication if the path is a packet path, i.e. a packet commitment, ack
+    /// or receipt.
+    pub fn is_packet_path(&self) -> bool {
+        matches!(self, Path::Commitment(_) | Path::Ack(_) | Path::Receipt(_))
+    }
+
+    /// Indication if the path is a sequence counter, i.e. one of:
+    /// - the client, connection and channel sequence counters, and
+    /// - the per-channel next send, receive and ack sequences.
+    ///
+    /// These are exactly the paths of [`StoreType::Sequence`].
+    pub fn is_sequence_counter(&self) -> bool {
+        matches!(
+            self,
+            Path::NextClientSequence(_)
+                | Path::NextConnectionSequence(_)
+                | Path::NextChannelSequence(_)
+                | Path::SeqSend(_)
+                | Path::SeqRecv(_)
+                | Path::SeqAck(_)
+        )
+    }
+
+    /// Returns the port identifier, channel identifier and sequence of a
+    /// packet path, if it is one.
+    pub fn packet_fields(&self) -> Option<(&PortId, &ChannelId, Sequence)> {
+        match self {
+            Path::Commitment(path) => Some((&path.port_id, &path.channel_id, path.sequence)),
+            Path::Ack(path) => Some((&path.port_id, &path.channel_id, path.sequence)),
+            Path::Receipt(path) => Some((&path.port_id, &path.channel_id, path.sequence)),
             _ => None,
         }
-    } else if components.len() == 4 || components.len() == 5 {
-        match components[2] {
-            CONSENSUS_STATE_PREFIX => {}
-            _ => return None,
+    }
+
+    /// Returns the client identifier embedded in the path, if any.
+    pub fn client_id(&self) -> Option<&ClientId> {
+        match self {
+            Path::ClientState(ClientStatePath(client_id))
+            | Path::ClientType(ClientTypePath(client_id))
+            | Path::ClientConnection(ClientConnectionPath(client_id))
+            | Path::ClientConsensusStatePrefix(ClientConsensusStatePrefixPath(client_id)) => {
+                Some(client_id)
+            }
+            Path::ClientConsensusState(path) => Some(&path.client_id),
+            Path::ClientUpdateTime(path) => Some(&path.client_id),
+            Path::ClientUpdateHeight(path) => Some(&path.client_id),
+            _ => None,
         }
+    }
 
-        let epoch_height: Vec<&str> = components[3].split('-').collect();
+    /// Replaces the client identifier embedded in the path, e.g. when moving
+    /// the keys of a substituted client. Returns whether the path carries a
+    /// client identifier, i.e. whether it was replaced.
+    pub fn replace_client_id(&mut self, new: ClientId) -> bool {
+        let client_id = match self {
+            Path::ClientState(ClientStatePath(client_id))
+            | Path::ClientType(ClientTypePath(client_id))
+            | Path::ClientConnection(ClientConnectionPath(client_id))
+            | Path::ClientConsensusStatePrefix(ClientConsensusStatePrefixPath(client_id)) => {
+                client_id
+            }
+            Path::ClientConsensusState(path) => &mut path.client_id,
+            Path::ClientUpdateTime(path) => &mut path.client_id,
+            Path::ClientUpdateHeight(path) => &mut path.client_id,
+            _ => return false,
+        };
+
+        *client_id = new;
+
+        true
+    }
 
-        if epoch_height.len() != 2 {
-            return None;
+    /// Returns the connection identifier embedded in the path, if any.
+    pub fn connection_id(&self) -> Option<&ConnectionId> {
+        match self {
+            Path::Connection(ConnectionPath(connection_id)) => Some(connection_id),
+            Path::InterchainAccount(path) => Some(&path.connection_id),
+            _ => None,
         }
+    }
 
-        let revision_number = epoch_height[0];
-        let revision_height = epoch_height[1];
+    /// Returns the port identifier embedded in the path, if any.
+    pub fn port_id(&self) -> Option<&PortId> {
+        match self {
+            Path::Ports(PortPath(port_id))
+            | Path::ChannelEnd(ChannelEndPath(port_id, _))
+            | Path::SeqSend(SeqSendPath(port_id, _))
+            | Path::SeqRecv(SeqRecvPath(port_id, _))
+            | Path::SeqAck(SeqAckPath(port_id, _)) => Some(port_id),
+            Path::Commitment(path) => Some(&path.port_id),
+            Path::Ack(path) => Some(&path.port_id),
+            Path::Receipt(path) => Some(&path.port_id),
+            _ => None,
+        }
+    }
 
-        let revision_number = revision_number.parse::<u64>().ok()?;
+    /// Returns the channel identifier embedded in the path, if any.
+    pub fn channel_id(&self) -> Option<&ChannelId> {
+        match self {
+            Path::ChannelEnd(ChannelEndPath(_, channel_id))
+            | Path::SeqSend(SeqSendPath(_, channel_id))
+            | Path::SeqRecv(SeqRecvPath(_, channel_id))
+            | Path::SeqAck(SeqAckPath(_, channel_id)) => Some(channel_id),
+            Path::Commitment(path) => Some(&path.channel_id),
+            Path::Ack(path) => Some(&path.channel_id),
+            Path::Receipt(path) => Some(&path.channel_id),
+            _ => None,
+        }
+    }
 
-        let revision_height = revision_height.parse::<u64>().ok()?;
+    /// Returns the port and channel identifiers of a channel-scoped path, i.e.
+    /// a channel end, one of the per-channel sequence counters, or a packet
+    /// commitment, ack or receipt. This allows grouping the paths of a channel.
+    pub fn channel_scope(&self) -> Option<(&PortId, &ChannelId)> {
+        match self {
+            Path::ChannelEnd(ChannelEndPath(port_id, channel_id))
+            | Path::SeqSend(SeqSendPath(port_id, channel_id))
+            | Path::SeqRecv(SeqRecvPath(port_id, channel_id))
+            | Path::SeqAck(SeqAckPath(port_id, channel_id)) => Some((port_id, channel_id)),
+            Path::Commitment(path) => Some((&path.port_id, &path.channel_id)),
+            Path::Ack(path) => Some((&path.port_id, &path.channel_id)),
+            Path::Receipt(path) => Some((&path.port_id, &path.channel_id)),
+            _ => None,
+        }
+    }
 
-        match components.len() {
-            4 => Some(
-                ClientConsensusStatePath {
-                    client_id,
-                    revision_number,
-                    revision_height,
-                }
-                .into(),
-            ),
-            5 => match components[4] {
-                PROCESSED_TIME => Some(
-                    ClientUpdateTimePath {
-                        client_id,
-                        revision_number,
-                        revision_height,
-                    }
-                    .into(),
-                ),
-                PROCESSED_HEIGHT => Some(
-                    ClientUpdateHeightPath {
-                        client_id,
-                        revision_number,
-                        revision_height,
-                    }
-                    .into(),
-                ),
-                _ => None,
-            },
+    /// Returns the [`ChannelEndPath`] of the channel owning a channel-scoped
+    /// path, e.g. to look up the channel end of a packet commitment.
+    pub fn to_channel_end(&self) -> Option<ChannelEndPath> {
+        self.channel_scope()
+            .map(|(port_id, channel_id)| ChannelEndPath::new(port_id, channel_id))
+    }
+
+    /// Replaces the channel identifier of a channel-scoped path by `to` if it
+    /// equals `from`, e.g. when migrating the keys of an upgraded channel.
+    /// Returns whether the path was rewritten.
+    pub fn rewrite_channel(&mut self, from: &ChannelId, to: ChannelId) -> bool {
+        let channel_id = match self {
+            Path::ChannelEnd(ChannelEndPath(_, channel_id))
+            | Path::SeqSend(SeqSendPath(_, channel_id))
+            | Path::SeqRecv(SeqRecvPath(_, channel_id))
+            | Path::SeqAck(SeqAckPath(_, channel_id)) => channel_id,
+            Path::Commitment(path) => &mut path.channel_id,
+            Path::Ack(path) => &mut path.channel_id,
+            Path::Receipt(path) => &mut path.channel_id,
+            _ => return false,
+        };
+
+        if channel_id != from {
+            return false;
+        }
+
+        *channel_id = to;
+
+        true
+    }
+
+    /// Replaces the port identifier of a port-bearing path by `to` if it
+    /// equals `from`, e.g. when the port of a module is renamed. Returns
+    /// whether the path was rewritten.
+    pub fn rewrite_port(&mut self, from: &PortId, to: PortId) -> bool {
+        let port_id = match self {
+            Path::Ports(PortPath(port_id))
+            | Path::ChannelEnd(ChannelEndPath(port_id, _))
+            | Path::SeqSend(SeqSendPath(port_id, _))
+            | Path::SeqRecv(SeqRecvPath(port_id, _))
+            | Path::SeqAck(SeqAckPath(port_id, _)) => port_id,
+            Path::Commitment(path) => &mut path.port_id,
+            Path::Ack(path) => &mut path.port_id,
+            Path::Receipt(path) => &mut path.port_id,
+            _ => return false,
+        };
+
+        if port_id != from {
+            return false;
+        }
+
+        *port_id = to;
+
+        true
+    }
+
+    /// Returns the numeric counter of the channel identifier embedded in the
+    /// path, e.g. 12 for "channel-12". This relies on the standard
+    /// "channel-{n}" format, hence returns `None` for any other identifier.
+    pub fn channel_sequence(&self) -> Option<u64> {
+        id_counter(self.channel_id()?.as_str(), ChannelId::prefix())
+    }
+
+    /// Returns the numeric counter of the connection identifier embedded in
+    /// the path, e.g. 3 for "connection-3". This relies on the standard
+    /// "connection-{n}" format, hence returns `None` for any other identifier.
+    pub fn connection_sequence(&self) -> Option<u64> {
+        id_counter(self.connection_id()?.as_str(), ConnectionId::prefix())
+    }
+
+    /// Returns the packet sequence embedded in the path, if any.
+    pub fn sequence(&self) -> Option<Sequence> {
+        match self {
+            Path::Commitment(path) => Some(path.sequence),
+            Path::Ack(path) => Some(path.sequence),
+            Path::Receipt(path) => Some(path.sequence),
             _ => None,
         }
-    } else {
-        None
     }
-}
 
-fn parse_connections(components: &[&str]) -> Option<Path> {
-    if components.len() != 2 {
-        return None;
+    /// Returns the plan height of an upgrade path, if any.
+    pub fn plan_height(&self) -> Option<u64> {
+        match self {
+            Path::UpgradeClientState(path) => Some(path.height),
+            Path::UpgradeConsensusState(path) => Some(path.height),
+            _ => None,
+        }
     }
 
-    let first = *components.first()?;
+    /// Creates the path of the upgraded client state at the given plan height,
+    /// under the default upgrade path.
+    pub fn upgraded_client_state(height: u64) -> Self {
+        UpgradeClientStatePath::new_with_default_path(height).into()
+    }
 
-    if first != CONNECTION_PREFIX {
-        return None;
+    /// Creates the path of the upgraded client consensus state at the given
+    /// plan height, under the default upgrade path.
+    pub fn upgraded_client_consensus_state(height: u64) -> Self {
+        UpgradeConsensusStatePath::new_with_default_path(height).into()
     }
 
-    let connection_id = *components.last()?;
+    /// Returns the number of `/`-separated components of the path, without
+    /// rendering it. The depth of an upgrade path depends on the number of
+    /// components of its upgrade path.
+    pub fn depth(&self) -> usize {
+        match self {
+            Path::NextClientSequence(_)
+            | Path::NextConnectionSequence(_)
+            | Path::NextChannelSequence(_)
+            | Path::Params(_) => 1,
+            Path::Connection(_) | Path::Ports(_) | Path::Capability(_) => 2,
+            Path::ClientState(_)
+            | Path::ClientType(_)
+            | Path::ClientConnection(_)
+            | Path::InterchainAccount(_)
+            | Path::ClientConsensusStatePrefix(_) => 3,
+            Path::ClientConsensusState(_) => 4,
+            Path::ClientUpdateTime(_)
+            | Path::ClientUpdateHeight(_)
+            | Path::ChannelEnd(_)
+            | Path::SeqSend(_)
+            | Path::SeqRecv(_)
+            | Path::SeqAck(_) => 5,
+            Path::Commitment(_) | Path::Ack(_) | Path::Receipt(_) => 7,
+            Path::UpgradeClientState(UpgradeClientStatePath { upgrade_path, .. })
+            | Path::UpgradeConsensusState(UpgradeConsensusStatePath { upgrade_path, .. }) => {
+                upgrade_path.split('/').count() + 2
+            }
+        }
+    }
 
-    let connection_id = ConnectionId::from_str(connection_id).ok()?;
+    /// Returns the `/`-separated components of the path, in order.
+    ///
+    /// Constant prefixes and identifiers are borrowed; only numeric
+    /// components, such as heights and sequences, are allocated.
+    pub fn components(&self) -> PathComponents<'_> {
+        match self {
+            Path::NextClientSequence(_) => PathComponents::new([NEXT_CLIENT_SEQUENCE.into()]),
+            Path::NextConnectionSequence(_) => {
+                PathComponents::new([NEXT_CONNECTION_SEQUENCE.into()])
+            }
+            Path::NextChannelSequence(_) => PathComponents::new([NEXT_CHANNEL_SEQUENCE.into()]),
+            Path::ClientState(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.0.as_str().into(),
+                CLIENT_STATE.into(),
+            ]),
+            Path::ClientType(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.0.as_str().into(),
+                CLIENT_TYPE.into(),
+            ]),
+            Path::ClientConsensusState(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.client_id.as_str().into(),
+                CONSENSUS_STATE_PREFIX.into(),
+                format!("{}-{}", path.revision_number, path.revision_height).into(),
+            ]),
+            Path::ClientUpdateTime(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.client_id.as_str().into(),
+                CONSENSUS_STATE_PREFIX.into(),
+                format!("{}-{}", path.revision_number, path.revision_height).into(),
+                PROCESSED_TIME.into(),
+            ]),
+            Path::ClientUpdateHeight(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.client_id.as_str().into(),
+                CONSENSUS_STATE_PREFIX.into(),
+                format!("{}-{}", path.revision_number, path.revision_height).into(),
+                PROCESSED_HEIGHT.into(),
+            ]),
+            Path::ClientConnection(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.0.as_str().into(),
+                CONNECTION_PREFIX.into(),
+            ]),
+            Path::Connection(path) => {
+                PathComponents::new([CONNECTION_PREFIX.into(), path.0.as_str().into()])
+            }
+            Path::Ports(path) => PathComponents::new([PORT_PREFIX.into(), path.0.as_str().into()]),
+            Path::ChannelEnd(path) => PathComponents::new([
+                CHANNEL_END_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.0.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.1.as_str().into(),
+            ]),
+            Path::SeqSend(path) => PathComponents::new([
+                NEXT_SEQ_SEND_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.0.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.1.as_str().into(),
+            ]),
+            Path::SeqRecv(path) => PathComponents::new([
+                NEXT_SEQ_RECV_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.0.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.1.as_str().into(),
+            ]),
+            Path::SeqAck(path) => PathComponents::new([
+                NEXT_SEQ_ACK_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.0.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.1.as_str().into(),
+            ]),
+            Path::Commitment(path) => PathComponents::new([
+                PACKET_COMMITMENT_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.port_id.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.channel_id.as_str().into(),
+                SEQUENCE_PREFIX.into(),
+                path.sequence.to_string().into(),
+            ]),
+            Path::Ack(path) => PathComponents::new([
+                PACKET_ACK_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.port_id.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.channel_id.as_str().into(),
+                SEQUENCE_PREFIX.into(),
+                path.sequence.to_string().into(),
+            ]),
+            Path::Receipt(path) => PathComponents::new([
+                PACKET_RECEIPT_PREFIX.into(),
+                PORT_PREFIX.into(),
+                path.port_id.as_str().into(),
+                CHANNEL_PREFIX.into(),
+                path.channel_id.as_str().into(),
+                SEQUENCE_PREFIX.into(),
+                path.sequence.to_string().into(),
+            ]),
+            Path::UpgradeClientState(path) => PathComponents::with_root(
+                &path.upgrade_path,
+                [path.height.to_string().into(), UPGRADED_CLIENT_STATE.into()],
+            ),
+            Path::UpgradeConsensusState(path) => PathComponents::with_root(
+                &path.upgrade_path,
+                [
+                    path.height.to_string().into(),
+                    UPGRADED_CLIENT_CONSENSUS_STATE.into(),
+                ],
+            ),
+            Path::InterchainAccount(path) => PathComponents::new([
+                INTERCHAIN_ACCOUNT_PREFIX.into(),
+                path.connection_id.as_str().into(),
+                path.owner.as_str().into(),
+            ]),
+            Path::Capability(path) => {
+                PathComponents::new([CAPABILITY_PREFIX.into(), path.0.to_string().into()])
+            }
+            Path::Params(path) => PathComponents::new([path.key().into()]),
+            Path::ClientConsensusStatePrefix(path) => PathComponents::new([
+                CLIENT_PREFIX.into(),
+                path.0.as_str().into(),
+                CONSENSUS_STATE_PREFIX.into(),
+            ]),
+        }
+    }
 
-    Some(ConnectionPath(connection_id).into())
-}
+    /// Writes the canonical key of the path, i.e. its string representation,
+    /// into the given writer. This allows appending keys into a reused buffer
+    /// without allocating an intermediate `String` for each of them.
+    pub fn write_key<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        write!(w, "{self}")
+    }
 
-fn parse_ports(components: &[&str]) -> Option<Path> {
-    if components.len() != 2 {
-        return None;
+    /// Appends the keys of the given paths into `out`, each followed by a
+    /// newline, e.g. when writing a snapshot of many paths at once.
+    pub fn render_all<'a>(paths: impl IntoIterator<Item = &'a Path>, out: &mut String) {
+        for path in paths {
+            // Writing into a `String` cannot fail.
+            let _ = path.write_key(out);
+            out.push('\n');
+        }
     }
 
-    let first = *components.first()?;
+    /// Converts the path into its string representation. Unlike `to_string`,
+    /// the string is allocated upfront with its final length, which avoids
+    /// reallocating it while formatting.
+    pub fn into_string(self) -> String {
+        let mut path = String::with_capacity(self.encoded_len());
+        // Writing into a `String` cannot fail.
+        let _ = self.write_key(&mut path);
+        path
+    }
 
-    if first != PORT_PREFIX {
-        return None;
+    /// into_bytes implementation
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.into_string().into_bytes()
     }
 
-    let port_id = *components.last()?;
+    /// Returns the 64-bit FNV-1a hash of the key bytes of the path.
+    ///
+    /// Unlike the derived [`Hash`], this only depends on the rendered key, so
+    /// it is stable across versions of this crate and across platforms.
+    pub fn stable_hash(&self) -> u64 {
+        let mut hasher = Fnv1aHasher(FNV1A_OFFSET_BASIS);
 
-    let port_id = PortId::from_str(port_id).ok()?;
+        // Writing to the hasher never fails.
+        let _ = self.write_key(&mut hasher);
 
-    Some(PortPath(port_id).into())
-}
+        hasher.0
+    }
 
-fn parse_channels(components: &[&str]) -> Option<SubPath> {
-    if components.len() != 2 {
-        return None;
+    /// Compares the paths by the byte-lexicographic order of their keys, i.e.
+    /// the order in which a store iterates over them. This differs from the
+    /// derived [`Ord`], which orders paths by variant first.
+    pub fn store_key_cmp(&self, other: &Path) -> core::cmp::Ordering {
+        self.to_string()
+            .as_bytes()
+            .cmp(other.to_string().as_bytes())
     }
 
-    let first = *components.first()?;
+    /// Returns the ABCI query path under which the path is queried on a
+    /// Cosmos SDK chain, i.e. [`IBC_QUERY_PATH`], or [`SDK_UPGRADE_QUERY_PATH`]
+    /// for the upgrade paths, which live in the upgrade sub-store.
+    ///
+    /// When querying a Tendermint full node over RPC, this is passed as the
+    /// `path` of the `abci_query` request, along with
+    /// [`Path::abci_query_data`] as its `data`.
+    pub fn abci_query_path(&self) -> &'static str {
+        match self {
+            Path::UpgradeClientState(_) | Path::UpgradeConsensusState(_) => SDK_UPGRADE_QUERY_PATH,
+            _ => IBC_QUERY_PATH,
+        }
+    }
 
-    if first != CHANNEL_PREFIX {
-        return None;
+    /// Returns the data of the ABCI query for the path, i.e. its key bytes. See
+    /// [`Path::abci_query_path`].
+    pub fn abci_query_data(&self) -> Vec<u8> {
+        self.clone().into_bytes()
     }
 
-    let channel_id = *components.last()?;
+    /// Returns the ICS-23 key path under which the path is proven when the
+    /// store is committed under the given prefix, e.g. "ibc". As per ICS-23,
+    /// the prefix is a separate element of the key path, so this returns the
+    /// prefix followed by the key of the path rather than their concatenation.
+    ///
+    /// This is the raw form of the `MerklePath` built by
+    /// `MerklePath::with_prefix` in the ICS-23 commitment types.
+    pub fn prefixed_key(&self, prefix: &[u8]) -> Vec<Vec<u8>> {
+        vec![prefix.to_vec(), self.clone().into_bytes()]
+    }
 
-    let channel_id = ChannelId::from_str(channel_id).ok()?;
+    /// Returns the key bytes of the path encoded in lowercase hex, as some
+    /// explorers display IBC keys.
+    #[cfg(feature = "key-encoding")]
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_string())
+    }
 
-    Some(SubPath::Channels(channel_id))
-}
+    /// Returns the key bytes of the path encoded in standard, padded base64.
+    #[cfg(feature = "key-encoding")]
+    pub fn to_base64(&self) -> String {
+        use base64::Engine;
 
-fn parse_sequences(components: &[&str]) -> Option<SubPath> {
-    if components.len() != 2 {
-        return None;
+        base64::prelude::BASE64_STANDARD.encode(self.to_string())
+    }
+
+    /// Encodes the string representation of the path as a borsh `String`, i.e.
+    /// prefixed with its length. Unlike the derived borsh encoding of [`Path`],
+    /// which is structural, this key is self-describing and can be nested in
+    /// other borsh-encoded structures.
+    #[cfg(feature = "borsh")]
+    pub fn borsh_key(&self) -> Vec<u8> {
+        let path = self.to_string();
+        let mut key = Vec::with_capacity(path.len() + 4);
+        // Serializing into a `Vec` cannot fail.
+        let _ = borsh::BorshSerialize::serialize(&path, &mut key);
+        key
     }
 
-    let first = *components.first()?;
+    /// Decodes a path from a key produced by [`Path::borsh_key`].
+    #[cfg(feature = "borsh")]
+    pub fn from_borsh_key(key: &[u8]) -> Result<Path, PathError> {
+        let path: String = borsh::from_slice(key).map_err(|e| PathError::InvalidBorshKey {
+            reason: e.to_string(),
+        })?;
 
-    if first != SEQUENCE_PREFIX {
-        return None;
+        Path::from_str(&path)
     }
 
-    let sequence_number = *components.last()?;
+    /// Returns the length in bytes of the key of the path, without rendering
+    /// it, e.g. to pre-size buffers or to estimate the cost of a store write.
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Path::NextClientSequence(_) => NEXT_CLIENT_SEQUENCE.len(),
+            Path::NextConnectionSequence(_) => NEXT_CONNECTION_SEQUENCE.len(),
+            Path::NextChannelSequence(_) => NEXT_CHANNEL_SEQUENCE.len(),
+            Path::ClientState(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.0.as_str().len(),
+                CLIENT_STATE.len(),
+            ]),
+            Path::ClientType(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.0.as_str().len(),
+                CLIENT_TYPE.len(),
+            ]),
+            Path::ClientConsensusState(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.client_id.as_str().len(),
+                CONSENSUS_STATE_PREFIX.len(),
+                revision_len(path.revision_number, path.revision_height),
+            ]),
+            Path::ClientUpdateTime(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.client_id.as_str().len(),
+                CONSENSUS_STATE_PREFIX.len(),
+                revision_len(path.revision_number, path.revision_height),
+                PROCESSED_TIME.len(),
+            ]),
+            Path::ClientUpdateHeight(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.client_id.as_str().len(),
+                CONSENSUS_STATE_PREFIX.len(),
+                revision_len(path.revision_number, path.revision_height),
+                PROCESSED_HEIGHT.len(),
+            ]),
+            Path::ClientConnection(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.0.as_str().len(),
+                CONNECTION_PREFIX.len(),
+            ]),
+            Path::Connection(path) => joined_len(&[CONNECTION_PREFIX.len(), path.0.as_str().len()]),
+            Path::Ports(path) => joined_len(&[PORT_PREFIX.len(), path.0.as_str().len()]),
+            Path::ChannelEnd(ChannelEndPath(port_id, channel_id))
+            | Path::SeqSend(SeqSendPath(port_id, channel_id))
+            | Path::SeqRecv(SeqRecvPath(port_id, channel_id))
+            | Path::SeqAck(SeqAckPath(port_id, channel_id)) => joined_len(&[
+                self.prefix().len(),
+                PORT_PREFIX.len(),
+                port_id.as_str().len(),
+                CHANNEL_PREFIX.len(),
+                channel_id.as_str().len(),
+            ]),
+            Path::Commitment(CommitmentPath {
+                port_id,
+                channel_id,
+                sequence,
+            })
+            | Path::Ack(AckPath {
+                port_id,
+                channel_id,
+                sequence,
+            })
+            | Path::Receipt(ReceiptPath {
+                port_id,
+                channel_id,
+                sequence,
+            }) => joined_len(&[
+                self.prefix().len(),
+                PORT_PREFIX.len(),
+                port_id.as_str().len(),
+                CHANNEL_PREFIX.len(),
+                channel_id.as_str().len(),
+                SEQUENCE_PREFIX.len(),
+                decimal_len(sequence.value()),
+            ]),
+            Path::UpgradeClientState(path) => joined_len(&[
+                path.upgrade_path.len(),
+                decimal_len(path.height),
+                UPGRADED_CLIENT_STATE.len(),
+            ]),
+            Path::UpgradeConsensusState(path) => joined_len(&[
+                path.upgrade_path.len(),
+                decimal_len(path.height),
+                UPGRADED_CLIENT_CONSENSUS_STATE.len(),
+            ]),
+            Path::InterchainAccount(path) => joined_len(&[
+                INTERCHAIN_ACCOUNT_PREFIX.len(),
+                path.connection_id.as_str().len(),
+                path.owner.len(),
+            ]),
+            Path::Capability(path) => joined_len(&[CAPABILITY_PREFIX.len(), decimal_len(path.0)]),
+            Path::Params(path) => path.key().len(),
+            Path::ClientConsensusStatePrefix(path) => joined_len(&[
+                CLIENT_PREFIX.len(),
+                path.0.as_str().len(),
+                CONSENSUS_STATE_PREFIX.len(),
+            ]),
+        }
+    }
 
-    match Sequence::from_str(sequence_number) {
-        Ok(seq) => Some(SubPath::Sequences(seq)),
-        Err(_) => None,
+    /// Checks that the path is semantically valid, i.e. that its embedded
+    /// identifiers and free-form components, such as interchain account owners
+    /// and upgrade paths, are well-formed. This holds iff the path round-trips
+    /// through its string representation.
+    ///
+    /// Paths decoded with serde, borsh or parity-scale-codec are only
+    /// structurally valid, since decoding does not check the format of their
+    /// identifiers. The same holds for paths assembled from such identifiers
+    /// through the public fields of the sub-paths. Such paths should hence be
+    /// validated before being written to a store.
+    pub fn validate(&self) -> Result<(), PathError> {
+        self.try_to_string().map(|_| ())
     }
-}
 
-fn parse_channel_ends(components: &[&str]) -> Option<Path> {
-    if components.len() != 5 {
-        return None;
+    /// Renders the key of the path, failing if it does not parse back into the
+    /// same path, e.g. because a hand-built identifier embeds a `/`. Unlike
+    /// `to_string`, this guarantees that the key cannot address another entry
+    /// of the store. See [`Path::validate`].
+    pub fn try_to_string(&self) -> Result<String, PathError> {
+        let path = self.to_string();
+
+        if Path::from_str(&path)? != *self {
+            return Err(PathError::TruncatedParseFailure {
+                path: path.as_str().into(),
+            });
+        }
+
+        Ok(path)
     }
 
-    let first = *components.first()?;
+    /// Returns the path its key parses back into, e.g. to deduplicate decoded
+    /// or hand-built paths along with parsed ones. Two paths addressing the
+    /// same store entry have the same canonical form, and parsed paths are
+    /// canonical by construction.
+    ///
+    /// A path whose key does not parse has no canonical form, and is returned
+    /// as is. Use [`Path::try_canonical`] to detect these.
+    pub fn canonical(self) -> Path {
+        self.try_canonical().unwrap_or(self)
+    }
 
-    if first != CHANNEL_END_PREFIX {
-        return None;
+    /// Returns the canonical form of the path, see [`Path::canonical`]. Fails
+    /// if the key of the path does not parse, i.e. if the path holds an
+    /// identifier or free-form component which [`Path::from_str`] rejects,
+    /// such as one embedding a `/` or a hand-built client identifier shorter
+    /// than ICS-24 allows.
+    pub fn try_canonical(&self) -> Result<Path, PathError> {
+        Path::from_str(&self.to_string())
     }
 
-    let port = parse_ports(&components[1..=2]);
-    let channel = parse_channels(&components[3..=4]);
+    /// Parses a newline-delimited list of paths, e.g. a dump of store keys.
+    /// Yields the zero-based line index of each path along with its parse
+    /// result, so that failing lines can be reported. Blank lines are skipped.
+    pub fn parse_many(input: &str) -> impl Iterator<Item = (usize, Result<Path, PathError>)> + '_ {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| (index, Path::from_str(line)))
+    }
 
-    let Some(Path::Ports(PortPath(port_id))) = port else {
-        return None;
-    };
+    /// Parses a path from its `/`-separated components, e.g. when they were
+    /// already split by an upstream framework, without joining them first.
+    ///
+    /// As with [`Path::from_str`], empty components are rejected. So are the
+    /// components which contain a `/`, as the key of their path would split
+    /// differently.
+    pub fn try_from_components(components: &[&str]) -> Result<Path, PathError> {
+        if components.len() > MAX_PATH_COMPONENTS {
+            return Err(PathError::TooManyComponents {
+                max: MAX_PATH_COMPONENTS,
+            });
+        }
 
-    let Some(SubPath::Channels(channel_id)) = channel else {
-        return None;
-    };
+        for (index, component) in components.iter().enumerate() {
+            if component.is_empty() {
+                return Err(PathError::EmptyComponent { index });
+            }
+            if component.contains('/') {
+                return Err(unexpected_component(component));
+            }
+        }
 
-    Some(ChannelEndPath(port_id, channel_id).into())
-}
+        parse_components(components, &StrictIdValidator)
+    }
 
-fn parse_seqs(components: &[&str]) -> Option<Path> {
-    if components.len() != 5 {
-        return None;
+    /// Parses a path like [`Path::from_str`], but matches the constant
+    /// components of paths regardless of their case, e.g. "nextsequencesend"
+    /// for "nextSequenceSend". They are normalized to their canonical form, so
+    /// that the returned path displays canonically.
+    ///
+    /// Only the components at the constant positions of each path layout are
+    /// normalized. Identifiers and free-form components, such as interchain
+    /// account owners, are kept as is, so that e.g. a port named "Channels"
+    /// still identifies the same port.
+    pub fn from_str_lenient(s: &str) -> Result<Path, PathError> {
+        let mut buffer = [""; MAX_PATH_COMPONENTS];
+
+        let components = split_components(s, &mut buffer)?;
+
+        let mut normalized = [""; MAX_PATH_COMPONENTS];
+        let normalized = &mut normalized[..components.len()];
+
+        normalized.copy_from_slice(components);
+
+        for index in keyword_positions(components) {
+            normalized[index] = normalize_keyword(components[index]);
+        }
+
+        parse_components(normalized, &StrictIdValidator)
     }
 
-    let first = *components.first()?;
+    /// Parses a path like [`Path::from_str`], but checks the identifiers it
+    /// contains with the given [`IdValidator`] instead of their `FromStr`
+    /// implementations, e.g. to accept the client identifiers of chains which
+    /// do not follow ICS-24:
+    ///
+    /// ```
+    /// use ibc_core_host_types::path::{Path, RelaxedIdValidator};
+    ///
+    /// let path = Path::from_str_with("clients/07-tm-0/clientState", RelaxedIdValidator).unwrap();
+    ///
+    /// assert_eq!(path.to_string(), "clients/07-tm-0/clientState");
+    /// ```
+    ///
+    /// Note that [`Path::validate`] rejects the paths holding identifiers
+    /// which [`Path::from_str`] does not accept.
+    pub fn from_str_with<V: IdValidator>(s: &str, validator: V) -> Result<Path, PathError> {
+        let mut buffer = [""; MAX_PATH_COMPONENTS];
+
+        parse_components(split_components(s, &mut buffer)?, &validator)
+    }
 
-    let port = parse_ports(&components[1..=2]);
-    let channel = parse_channels(&components[3..=4]);
+    /// Parses a path from its byte representation, i.e. the inverse of
+    /// [`Path::into_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Path, PathError> {
+        let path = core::str::from_utf8(bytes).map_err(PathError::InvalidUtf8)?;
 
-    let Some(Path::Ports(PortPath(port_id))) = port else {
-        return None;
-    };
+        Path::from_str(path)
+    }
+}
 
-    let Some(SubPath::Channels(channel_id)) = channel else {
-        return None;
-    };
+impl TryFrom<&[u8]> for Path {
+    type Error = PathError;
 
-    match first {
-        NEXT_SEQ_SEND_PREFIX => Some(SeqSendPath(port_id, channel_id).into()),
-        NEXT_SEQ_RECV_PREFIX => Some(SeqRecvPath(port_id, channel_id).into()),
-        NEXT_SEQ_ACK_PREFIX => Some(SeqAckPath(port_id, channel_id).into()),
-        _ => None,
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Path::from_bytes(bytes)
     }
 }
 
-fn parse_commitments(components: &[&str]) -> Option<Path> {
-    if components.len() != 7 {
-        return None;
+impl TryFrom<&str> for Path {
+    type Error = PathError;
+
+    fn try_from(path: &str) -> Result<Self, Self::Error> {
+        Path::from_str(path)
     }
+}
 
-    let first = *components.first()?;
+impl TryFrom<String> for Path {
+    type Error = PathError;
 
-    if first != PACKET_COMMITMENT_PREFIX {
-        return None;
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        Path::from_str(&path)
     }
+}
 
-    let port = parse_ports(&components[1..=2]);
-    let channel = parse_channels(&components[3..=4]);
-    let sequence = parse_sequences(&components[5..]);
+/// Compares the rendered pieces of a path against the remainder of an expected
+/// string as they are written, failing on the first mismatch.
+struct KeyComparator<'a> {
+    rest: &'a str,
+}
 
-    let Some(Path::Ports(PortPath(port_id))) = port else {
-        return None;
-    };
+impl core::fmt::Write for KeyComparator<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.rest = self.rest.strip_prefix(s).ok_or(core::fmt::Error)?;
 
-    let Some(SubPath::Channels(channel_id)) = channel else {
-        return None;
-    };
+        Ok(())
+    }
+}
 
-    let Some(SubPath::Sequences(sequence)) = sequence else {
-        return None;
-    };
+const FNV1A_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
 
-    Some(
-        CommitmentPath {
-            port_id,
-            channel_id,
-            sequence,
+const FNV1A_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Feeds the rendered pieces of a path into a 64-bit FNV-1a hash.
+struct Fnv1aHasher(u64);
+
+impl core::fmt::Write for Fnv1aHasher {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for byte in s.bytes() {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV1A_PRIME);
         }
-        .into(),
-    )
-}
 
-fn parse_acks(components: &[&str]) -> Option<Path> {
-    if components.len() != 7 {
-        return None;
+        Ok(())
     }
+}
 
-    let first = *components.first()?;
+/// Compares the path to its string representation without allocating.
+impl PartialEq<str> for Path {
+    fn eq(&self, other: &str) -> bool {
+        let mut comparator = KeyComparator { rest: other };
 
-    if first != PACKET_ACK_PREFIX {
-        return None;
+        self.write_key(&mut comparator).is_ok() && comparator.rest.is_empty()
     }
+}
 
-    let port = parse_ports(&components[1..=2]);
-    let channel = parse_channels(&components[3..=4]);
-    let sequence = parse_sequences(&components[5..]);
+impl PartialEq<&str> for Path {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
 
-    let Some(Path::Ports(PortPath(port_id))) = port else {
-        return None;
-    };
+impl PartialEq<String> for Path {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
 
-    let Some(SubPath::Channels(channel_id)) = channel else {
-        return None;
+/// Implements the extraction of each sub-path from a [`Path`], the inverse of
+/// the derived `From` conversions.
+macro_rules! impl_try_from_path {
+    ($($variant:ident($sub_path:ty)),* $(,)?) => {
+        $(
+            impl TryFrom<Path> for $sub_path {
+                type Error = PathError;
+
+                fn try_from(path: Path) -> Result<Self, Self::Error> {
+                    match path {
+                        Path::$variant(path) => Ok(path),
+                        path => Err(PathError::WrongVariant {
+                            expected: PathKind::$variant,
+                            found: path.kind(),
+                        }),
+                    }
+                }
+            }
+        )*
     };
+}
 
-    let Some(SubPath::Sequences(sequence)) = sequence else {
-        return None;
-    };
+impl_try_from_path!(
+    NextClientSequence(NextClientSequencePath),
+    NextConnectionSequence(NextConnectionSequencePath),
+    NextChannelSequence(NextChannelSequencePath),
+    ClientState(ClientStatePath),
+    ClientConsensusState(ClientConsensusStatePath),
+    ClientUpdateTime(ClientUpdateTimePath),
+    ClientUpdateHeight(ClientUpdateHeightPath),
+    ClientConnection(ClientConnectionPath),
+    Connection(ConnectionPath),
+    Ports(PortPath),
+    ChannelEnd(ChannelEndPath),
+    SeqSend(SeqSendPath),
+    SeqRecv(SeqRecvPath),
+    SeqAck(SeqAckPath),
+    Commitment(CommitmentPath),
+    Ack(AckPath),
+    Receipt(ReceiptPath),
+    UpgradeClientState(UpgradeClientStatePath),
+    UpgradeConsensusState(UpgradeConsensusStatePath),
+    InterchainAccount(InterchainAccountPath),
+    Capability(CapabilityPath),
+    Params(ParamsPath),
+    ClientConsensusStatePrefix(ClientConsensusStatePrefixPath),
+    ClientType(ClientTypePath),
+);
+
+/// Writes the string representation of a path, or of one of its sub-paths,
+/// into a [`core::fmt::Write`] without allocating. This allows rendering any of
+/// them through a single generic bound.
+pub trait PathSegmentWrite: Display {
+    fn write_to<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        write!(w, "{self}")
+    }
+}
 
-    Some(
-        AckPath {
-            port_id,
-            channel_id,
-            sequence,
+impl PathSegmentWrite for Path {
+    fn write_to<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        self.write_key(w)
+    }
+}
+
+impl PathSegmentWrite for NextClientSequencePath {}
+impl PathSegmentWrite for NextConnectionSequencePath {}
+impl PathSegmentWrite for NextChannelSequencePath {}
+impl PathSegmentWrite for ClientStatePath {}
+impl PathSegmentWrite for ClientTypePath {}
+impl PathSegmentWrite for ClientConsensusStatePath {}
+impl PathSegmentWrite for ClientUpdateTimePath {}
+impl PathSegmentWrite for ClientUpdateHeightPath {}
+impl PathSegmentWrite for ClientConnectionPath {}
+impl PathSegmentWrite for ConnectionPath {}
+impl PathSegmentWrite for PortPath {}
+impl PathSegmentWrite for ChannelEndPath {}
+impl PathSegmentWrite for SeqSendPath {}
+impl PathSegmentWrite for SeqRecvPath {}
+impl PathSegmentWrite for SeqAckPath {}
+impl PathSegmentWrite for CommitmentPath {}
+impl PathSegmentWrite for AckPath {}
+impl PathSegmentWrite for ReceiptPath {}
+impl PathSegmentWrite for UpgradeClientStatePath {}
+impl PathSegmentWrite for UpgradeConsensusStatePath {}
+impl PathSegmentWrite for InterchainAccountPath {}
+impl PathSegmentWrite for CapabilityPath {}
+impl PathSegmentWrite for ParamsPath {}
+impl PathSegmentWrite for ClientConsensusStatePrefixPath {}
+
+/// A [`Path`] which memoizes its string representation, for hot paths which
+/// render the same path repeatedly. The string is computed on the first call
+/// to [`CachedPath::as_str`] and reused afterwards.
+///
+/// The path can only be accessed immutably, through `Deref`, so that the
+/// cached string never goes stale.
+#[derive(Clone, Debug)]
+pub struct CachedPath {
+    path: Path,
+    rendered: OnceCell<String>,
+}
+
+impl CachedPath {
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+            rendered: OnceCell::new(),
         }
-        .into(),
-    )
+    }
+
+    /// Returns the string representation of the path, computing it on the
+    /// first call only.
+    pub fn as_str(&self) -> &str {
+        self.rendered.get_or_init(|| self.path.to_string())
+    }
+
+    /// Returns the wrapped path, discarding the cached string.
+    pub fn into_inner(self) -> Path {
+        self.path
+    }
 }
 
-fn parse_receipts(components: &[&str]) -> Option<Path> {
-    if components.len() != 7 {
-        return None;
+impl From<Path> for CachedPath {
+    fn from(path: Path) -> Self {
+        Self::new(path)
+    }
+}
+
+impl Deref for CachedPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Display for CachedPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The iterator over the components of a [`Path`], returned by
+/// [`Path::components`].
+///
+/// Apart from the upgrade path root of upgrade paths, which is split lazily,
+/// the components are held inline, since no path has more than
+/// `MAX_PATH_COMPONENTS` of them.
+#[derive(Clone, Debug)]
+pub struct PathComponents<'a> {
+    root: Option<core::str::Split<'a, char>>,
+    components: [Cow<'a, str>; MAX_PATH_COMPONENTS],
+    remaining: Range<usize>,
+}
+
+impl<'a> PathComponents<'a> {
+    fn new<const N: usize>(components: [Cow<'a, str>; N]) -> Self {
+        let mut buffer: [Cow<'a, str>; MAX_PATH_COMPONENTS] = Default::default();
+
+        for (slot, component) in buffer.iter_mut().zip(components) {
+            *slot = component;
+        }
+
+        Self {
+            root: None,
+            components: buffer,
+            remaining: 0..N,
+        }
+    }
+
+    fn with_root<const N: usize>(root: &'a str, components: [Cow<'a, str>; N]) -> Self {
+        Self {
+            root: Some(root.split('/')),
+            ..Self::new(components)
+        }
+    }
+}
+
+impl<'a> Iterator for PathComponents<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(component) = self.root.as_mut().and_then(Iterator::next) {
+            return Some(Cow::Borrowed(component));
+        }
+
+        self.root = None;
+
+        self.remaining
+            .next()
+            .map(|index| core::mem::take(&mut self.components[index]))
+    }
+}
+
+/// A [`Path`] ordered by the bytes of its key, as returned by
+/// [`Path::store_key_cmp`], rather than by the derived [`Ord`] of `Path`,
+/// which orders paths by variant first. Iterating over a
+/// `BTreeMap<ByteOrderedPath, _>` hence follows the order of the store.
+///
+/// Distinct paths rendering to the same key, which are necessarily invalid,
+/// are ordered by the derived `Ord`, so that the ordering is consistent with
+/// equality.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, From, Display)]
+pub struct ByteOrderedPath(pub Path);
+
+impl PartialOrd for ByteOrderedPath {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByteOrderedPath {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0
+            .store_key_cmp(&other.0)
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+/// A prefix of an offending path component, stored inline in [`PathError`].
+///
+/// Keys may come from untrusted sources and be arbitrarily long, so errors
+/// only keep their first [`PathFragment::CAPACITY`] bytes, truncated on a
+/// character boundary. This keeps the error path free of allocations, at the
+/// cost of losing the tail of overlong components.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PathFragment {
+    bytes: [u8; PathFragment::CAPACITY],
+    len: u8,
+    truncated: bool,
+}
+
+impl PathFragment {
+    /// The maximum number of bytes kept from a component.
+    pub const CAPACITY: usize = 32;
+
+    /// Keeps the longest prefix of the component which fits into the capacity.
+    pub fn new(component: &str) -> Self {
+        let mut len = component.len().min(Self::CAPACITY);
+
+        while !component.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut bytes = [0; Self::CAPACITY];
+        bytes[..len].copy_from_slice(&component.as_bytes()[..len]);
+
+        Self {
+            bytes,
+            len: len as u8,
+            truncated: len < component.len(),
+        }
+    }
+
+    /// Returns the kept prefix of the component.
+    pub fn as_str(&self) -> &str {
+        // The prefix is always cut on a character boundary.
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    /// Indication if the component was longer than the kept prefix.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl From<&str> for PathFragment {
+    fn from(component: &str) -> Self {
+        Self::new(component)
+    }
+}
+
+impl PartialEq<str> for PathFragment {
+    fn eq(&self, other: &str) -> bool {
+        !self.truncated && self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PathFragment {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Display for PathFragment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())?;
+
+        if self.truncated {
+            f.write_str("...")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for PathFragment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self.as_str())?;
+
+        if self.truncated {
+            f.write_str("...")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, displaydoc::Display)]
+#[ignore_extra_doc_attributes]
+pub enum PathError {
+    /// `{path}` could not be parsed into a Path
+    ///
+    /// Kept for backward compatibility only: this crate no longer returns it,
+    /// and reports a [`PathError::TruncatedParseFailure`] instead.
+    ParseFailure { path: String },
+    /// `{path}` could not be parsed into a Path
+    ///
+    /// Only holds the first [`PathFragment::CAPACITY`] bytes of the path,
+    /// inline, so that reporting the failure does not copy the whole path, at
+    /// the cost of losing the tail of an overlong one.
+    TruncatedParseFailure { path: PathFragment },
+    /// path bytes are not valid UTF-8: {0}
+    InvalidUtf8(Utf8Error),
+    /// path has an empty component at index {index}
+    EmptyComponent { index: usize },
+    /// path has more than {max} components
+    TooManyComponents { max: usize },
+    /// unknown path prefix `{prefix}`
+    UnknownPrefix { prefix: PathFragment },
+    /// expected {expected} path components, found {found}
+    WrongComponentCount { expected: usize, found: usize },
+    /// unexpected path component `{component}`
+    UnexpectedComponent { component: PathFragment },
+    /// invalid height `{height}`
+    InvalidHeight { height: PathFragment },
+    /// height `{height}` overflows a u64
+    HeightOverflow { height: PathFragment },
+    /// invalid capability index `{index}`
+    InvalidCapabilityIndex { index: PathFragment },
+    /// invalid borsh-encoded path key: {reason}
+    InvalidBorshKey { reason: String },
+    /// wrong path variant: expected {expected:?}, found {found:?}
+    WrongVariant { expected: PathKind, found: PathKind },
+    /// expected the `ports` segment of a channel-scoped path, found `{component}`
+    MissingPortSegment { component: PathFragment },
+    /// invalid encoded list of client connections: {reason}
+    InvalidClientConnections { reason: String },
+    /// invalid client identifier `{component}`: {source}
+    InvalidClientId {
+        component: PathFragment,
+        source: IdentifierError,
+    },
+    /// invalid connection identifier `{component}`: {source}
+    InvalidConnectionId {
+        component: PathFragment,
+        source: IdentifierError,
+    },
+    /// invalid port identifier `{component}`: {source}
+    InvalidPortId {
+        component: PathFragment,
+        source: IdentifierError,
+    },
+    /// invalid channel identifier `{component}`: {source}
+    InvalidChannelId {
+        component: PathFragment,
+        source: IdentifierError,
+    },
+    /// invalid sequence `{component}`: {source}
+    InvalidSequence {
+        component: PathFragment,
+        source: IdentifierError,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUtf8(e) => Some(e),
+            Self::InvalidClientId { source, .. }
+            | Self::InvalidConnectionId { source, .. }
+            | Self::InvalidPortId { source, .. }
+            | Self::InvalidChannelId { source, .. }
+            | Self::InvalidSequence { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The FromStr trait allows paths encoded as strings to be parsed into Paths.
+///
+/// A single leading `/`, as found in some ABCI query and proof paths, is
+/// tolerated and ignored. Any other empty component is rejected.
+///
+/// Identifiers are validated by their own `FromStr` implementations. Client
+/// identifiers are only checked against the ICS-24 character set and length
+/// bounds, so that those with a client type unknown to this crate, e.g.
+/// "99-custom-0", are accepted as is. Connection and channel identifiers must
+/// in addition be of the `connection-{n}` and `channel-{n}` form. See
+/// [`Path::from_str_with`] to relax these checks.
+impl FromStr for Path {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Path::from_str_with(s, StrictIdValidator)
+    }
+}
+
+/// Validates the identifiers embedded in the paths parsed by
+/// [`Path::from_str_with`]. Each check defaults to the one of the identifier's
+/// `FromStr` implementation, so that only the relaxed ones need overriding.
+pub trait IdValidator {
+    fn validate_client_id(&self, id: &str) -> Result<(), IdentifierError> {
+        validate_client_identifier(id)
+    }
+
+    fn validate_connection_id(&self, id: &str) -> Result<(), IdentifierError> {
+        validate_connection_identifier(id)
+    }
+
+    fn validate_port_id(&self, id: &str) -> Result<(), IdentifierError> {
+        validate_port_identifier(id)
+    }
+
+    fn validate_channel_id(&self, id: &str) -> Result<(), IdentifierError> {
+        validate_channel_identifier(id)
+    }
+}
+
+/// Validates identifiers as their `FromStr` implementations do, which is what
+/// [`Path::from_str`] uses.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StrictIdValidator;
+
+impl IdValidator for StrictIdValidator {}
+
+/// Only requires identifiers to be non-empty and free of `/`, e.g. for client
+/// identifiers shorter than the 9 characters required by ICS-24.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RelaxedIdValidator;
+
+impl RelaxedIdValidator {
+    fn validate(id: &str) -> Result<(), IdentifierError> {
+        if id.is_empty() {
+            return Err(IdentifierError::InvalidLength {
+                actual: id.into(),
+                min: 1,
+                max: u64::MAX,
+            });
+        }
+        if id.contains('/') {
+            return Err(IdentifierError::InvalidCharacter(id.into()));
+        }
+
+        Ok(())
+    }
+}
+
+impl IdValidator for RelaxedIdValidator {
+    fn validate_client_id(&self, id: &str) -> Result<(), IdentifierError> {
+        Self::validate(id)
+    }
+
+    fn validate_connection_id(&self, id: &str) -> Result<(), IdentifierError> {
+        Self::validate(id)
+    }
+
+    fn validate_port_id(&self, id: &str) -> Result<(), IdentifierError> {
+        Self::validate(id)
+    }
+
+    fn validate_channel_id(&self, id: &str) -> Result<(), IdentifierError> {
+        Self::validate(id)
+    }
+}
+
+/// Parses an upgraded client state path on its own, e.g. when working with
+/// the upgrade sub-store only.
+impl FromStr for UpgradeClientStatePath {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buffer = [""; MAX_PATH_COMPONENTS];
+
+        let (upgrade_path, height) =
+            parse_upgrade_path(split_components(s, &mut buffer)?, UPGRADED_CLIENT_STATE)?;
+
+        Ok(Self::new(upgrade_path, height))
+    }
+}
+
+/// Parses an upgraded consensus state path on its own, e.g. when working with
+/// the upgrade sub-store only.
+impl FromStr for UpgradeConsensusStatePath {
+    type Err = PathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buffer = [""; MAX_PATH_COMPONENTS];
+
+        let (upgrade_path, height) = parse_upgrade_path(
+            split_components(s, &mut buffer)?,
+            UPGRADED_CLIENT_CONSENSUS_STATE,
+        )?;
+
+        Ok(Self::new(upgrade_path, height))
+    }
+}
+
+/// Implements the parsing of each sub-path on its own, by parsing a [`Path`]
+/// and extracting the sub-path from it. The upgrade paths, which may live
+/// under a custom upgrade path, implement their own parsing above.
+macro_rules! impl_from_str_for_sub_path {
+    ($($sub_path:ty),* $(,)?) => {
+        $(
+            impl FromStr for $sub_path {
+                type Err = PathError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Path::from_str(s)?.try_into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str_for_sub_path!(
+    NextClientSequencePath,
+    NextConnectionSequencePath,
+    NextChannelSequencePath,
+    ClientStatePath,
+    ClientTypePath,
+    ClientConsensusStatePath,
+    ClientUpdateTimePath,
+    ClientUpdateHeightPath,
+    ClientConnectionPath,
+    ConnectionPath,
+    PortPath,
+    ChannelEndPath,
+    SeqSendPath,
+    SeqRecvPath,
+    SeqAckPath,
+    CommitmentPath,
+    AckPath,
+    ReceiptPath,
+    InterchainAccountPath,
+    CapabilityPath,
+    ParamsPath,
+    ClientConsensusStatePrefixPath,
+);
+
+/// Splits a path into its components, tolerating a single leading `/`.
+///
+/// No valid path has more than `MAX_PATH_COMPONENTS` components, so they are
+/// collected into a fixed-size buffer rather than a `Vec`, keeping the parsing
+/// free of heap allocations.
+fn split_components<'a, 'b>(
+    s: &'a str,
+    buffer: &'b mut [&'a str; MAX_PATH_COMPONENTS],
+) -> Result<&'b [&'a str], PathError> {
+    let s = s.strip_prefix('/').unwrap_or(s);
+    let mut len = 0;
+
+    for component in s.split('/') {
+        if len == MAX_PATH_COMPONENTS {
+            // Bail out before looking at the rest of the input, which may be
+            // arbitrarily large if it comes from an untrusted source.
+            return Err(PathError::TooManyComponents {
+                max: MAX_PATH_COMPONENTS,
+            });
+        }
+        if component.is_empty() {
+            return Err(PathError::EmptyComponent { index: len });
+        }
+        buffer[len] = component;
+        len += 1;
+    }
+
+    Ok(&buffer[..len])
+}
+
+/// (De)serializes a [`Path`] through its string representation, e.g.
+/// `"clients/07-tendermint-0/clientState"`, for interoperability with
+/// implementations that exchange paths as plain strings.
+///
+/// Note that this differs from the derived serde implementations of [`Path`]
+/// and its sub-paths, which (de)serialize them field by field. Use it with
+/// `#[serde(with = "path_as_string")]`, along with `#[schemars(with = "String")]`
+/// for the JSON schema to describe the string form as well.
+#[cfg(feature = "serde")]
+pub mod path_as_string {
+    use serde::{Deserializer, Serializer};
+
+    use super::Path;
+
+    pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        ibc_primitives::serializers::serialize(path, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Path, D::Error> {
+        ibc_primitives::serializers::deserialize(deserializer)
+    }
+}
+
+/// [`proptest`] strategies generating well-formed paths of every variant.
+///
+/// Identifiers are derived from numeric counters where possible, so that
+/// shrinking a failing case always yields valid paths. Counters, heights and
+/// sequences shrink toward zero, client types toward "07-tendermint" and port
+/// identifiers toward "transfer". Free-form identifiers are filtered through
+/// their validation on every shrinking step, so they remain valid as well.
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const CLIENT_TYPES: &[&str] = &["07-tendermint", "08-wasm", "06-solomachine"];
+
+    pub fn client_id() -> impl Strategy<Value = ClientId> {
+        (prop::sample::select(CLIENT_TYPES), any::<u64>())
+            .prop_filter_map("invalid client identifier", |(client_type, counter)| {
+                ClientId::new(client_type, counter).ok()
+            })
+    }
+
+    pub fn connection_id() -> impl Strategy<Value = ConnectionId> {
+        any::<u64>().prop_map(ConnectionId::new)
+    }
+
+    pub fn port_id() -> impl Strategy<Value = PortId> {
+        prop_oneof![
+            Just(PortId::transfer()),
+            "[a-zA-Z0-9._+#<>\\[\\]-]{2,64}".prop_filter_map("invalid port identifier", |port| {
+                PortId::from_str(&port).ok()
+            }),
+        ]
+    }
+
+    pub fn channel_id() -> impl Strategy<Value = ChannelId> {
+        any::<u64>().prop_map(ChannelId::new)
+    }
+
+    pub fn sequence() -> impl Strategy<Value = Sequence> {
+        any::<u64>().prop_map(Sequence::from)
+    }
+
+    pub fn upgrade_path() -> impl Strategy<Value = String> {
+        prop_oneof![
+            Just(UPGRADED_IBC_STATE.to_string()),
+            "[a-zA-Z]{1,16}".prop_map(|root| format!("upgrade/{root}")),
+        ]
+    }
+
+    /// Generates a [`Path`] of any variant.
+    pub fn path() -> impl Strategy<Value = Path> {
+        prop_oneof![
+            Just(Path::NextClientSequence(NextClientSequencePath)),
+            Just(Path::NextConnectionSequence(NextConnectionSequencePath)),
+            Just(Path::NextChannelSequence(NextChannelSequencePath)),
+            client_id().prop_map(|client_id| Path::ClientState(ClientStatePath(client_id))),
+            client_id().prop_map(|client_id| Path::ClientType(ClientTypePath(client_id))),
+            (client_id(), any::<u64>(), any::<u64>()).prop_map(
+                |(client_id, revision_number, revision_height)| {
+                    Path::ClientConsensusState(ClientConsensusStatePath {
+                        client_id,
+                        revision_number,
+                        revision_height,
+                    })
+                }
+            ),
+            (client_id(), any::<u64>(), any::<u64>()).prop_map(
+                |(client_id, revision_number, revision_height)| {
+                    Path::ClientUpdateTime(ClientUpdateTimePath {
+                        client_id,
+                        revision_number,
+                        revision_height,
+                    })
+                }
+            ),
+            (client_id(), any::<u64>(), any::<u64>()).prop_map(
+                |(client_id, revision_number, revision_height)| {
+                    Path::ClientUpdateHeight(ClientUpdateHeightPath {
+                        client_id,
+                        revision_number,
+                        revision_height,
+                    })
+                }
+            ),
+            client_id()
+                .prop_map(|client_id| Path::ClientConnection(ClientConnectionPath(client_id))),
+            connection_id()
+                .prop_map(|connection_id| Path::Connection(ConnectionPath(connection_id))),
+            port_id().prop_map(|port_id| Path::Ports(PortPath(port_id))),
+            (port_id(), channel_id()).prop_map(|(port_id, channel_id)| {
+                Path::ChannelEnd(ChannelEndPath(port_id, channel_id))
+            }),
+            (port_id(), channel_id())
+                .prop_map(|(port_id, channel_id)| Path::SeqSend(SeqSendPath(port_id, channel_id))),
+            (port_id(), channel_id())
+                .prop_map(|(port_id, channel_id)| Path::SeqRecv(SeqRecvPath(port_id, channel_id))),
+            (port_id(), channel_id())
+                .prop_map(|(port_id, channel_id)| Path::SeqAck(SeqAckPath(port_id, channel_id))),
+            (port_id(), channel_id(), sequence()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Commitment(CommitmentPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                })
+            }),
+            (port_id(), channel_id(), sequence()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Ack(AckPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                })
+            }),
+            (port_id(), channel_id(), sequence()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Receipt(ReceiptPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                })
+            }),
+            (upgrade_path(), any::<u64>()).prop_map(|(upgrade_path, height)| {
+                Path::UpgradeClientState(UpgradeClientStatePath::new(upgrade_path, height))
+            }),
+            (upgrade_path(), any::<u64>()).prop_map(|(upgrade_path, height)| {
+                Path::UpgradeConsensusState(UpgradeConsensusStatePath::new(upgrade_path, height))
+            }),
+            (connection_id(), "[^/]{1,64}").prop_map(|(connection_id, owner)| {
+                Path::InterchainAccount(InterchainAccountPath {
+                    connection_id,
+                    owner,
+                })
+            }),
+            any::<u64>().prop_map(|index| Path::Capability(CapabilityPath(index))),
+            prop_oneof![
+                Just(ParamsPath::Client),
+                Just(ParamsPath::Connection),
+                Just(ParamsPath::Channel),
+            ]
+            .prop_map(Path::Params),
+            client_id().prop_map(|client_id| {
+                Path::ClientConsensusStatePrefix(ClientConsensusStatePrefixPath(client_id))
+            }),
+        ]
+    }
+}
+
+/// Extracts the counter of an identifier in the "{prefix}-{counter}" format.
+fn id_counter(id: &str, prefix: &str) -> Option<u64> {
+    let counter = id.strip_prefix(prefix)?.strip_prefix('-')?;
+
+    if !is_canonical_decimal(counter) {
+        return None;
+    }
+
+    counter.parse().ok()
+}
+
+/// Checks that a numeric component carries neither a sign nor leading zeros,
+/// which `u64::from_str` accepts but which would not render back into the same
+/// component.
+fn is_canonical_decimal(component: &str) -> bool {
+    !matches!(component.as_bytes(), [b'+' | b'-', ..] | [b'0', _, ..])
+}
+
+/// Dispatches the path components to the parser of the path kind identified
+/// by the leading component. Upgrade paths are rooted in a configurable upgrade
+/// path, and are therefore identified by their trailing component.
+fn parse_components(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    let first = *components
+        .first()
+        .ok_or(PathError::EmptyComponent { index: 0 })?;
+
+    match first {
+        NEXT_CLIENT_SEQUENCE | NEXT_CONNECTION_SEQUENCE | NEXT_CHANNEL_SEQUENCE => {
+            parse_next_sequence(components)
+        }
+        CLIENT_PREFIX => parse_client_paths(components, ids),
+        CONNECTION_PREFIX => parse_connections(components, ids),
+        PORT_PREFIX => parse_ports(components, ids),
+        CHANNEL_END_PREFIX => parse_channel_ends(components, ids),
+        NEXT_SEQ_SEND_PREFIX | NEXT_SEQ_RECV_PREFIX | NEXT_SEQ_ACK_PREFIX => {
+            parse_seqs(components, ids)
+        }
+        PACKET_COMMITMENT_PREFIX => parse_commitments(components, ids),
+        PACKET_ACK_PREFIX => parse_acks(components, ids),
+        PACKET_RECEIPT_PREFIX => parse_receipts(components, ids),
+        INTERCHAIN_ACCOUNT_PREFIX => parse_interchain_accounts(components, ids),
+        CAPABILITY_PREFIX => parse_capabilities(components),
+        CLIENT_PARAMS | CONNECTION_PARAMS | CHANNEL_PARAMS => parse_params(components),
+        _ => match components.last() {
+            Some(&UPGRADED_CLIENT_STATE) => parse_upgrade_client_state(components),
+            Some(&UPGRADED_CLIENT_CONSENSUS_STATE) => parse_upgrade_consensus_state(components),
+            _ => Err(PathError::UnknownPrefix {
+                prefix: first.into(),
+            }),
+        },
+    }
+}
+
+/// Returns the positions of the constant components in the layout of the
+/// path identified by its leading component, matched regardless of case, see
+/// [`parse_components`]. Upgrade paths are rooted in a free-form upgrade path,
+/// of which only the conventional [`UPGRADED_IBC_STATE`] root is constant, and
+/// end in a constant leaf.
+fn keyword_positions(components: &[&str]) -> impl Iterator<Item = usize> {
+    let prefix = components
+        .first()
+        .map_or("", |first| normalize_keyword(first));
+
+    let (positions, leaf): (&[usize], bool) = match prefix {
+        CLIENT_PREFIX => (&[0, 2, 4], false),
+        CHANNEL_END_PREFIX | NEXT_SEQ_SEND_PREFIX | NEXT_SEQ_RECV_PREFIX | NEXT_SEQ_ACK_PREFIX => {
+            (&[0, 1, 3], false)
+        }
+        PACKET_COMMITMENT_PREFIX | PACKET_ACK_PREFIX | PACKET_RECEIPT_PREFIX => {
+            (&[0, 1, 3, 5], false)
+        }
+        NEXT_CLIENT_SEQUENCE
+        | NEXT_CONNECTION_SEQUENCE
+        | NEXT_CHANNEL_SEQUENCE
+        | CONNECTION_PREFIX
+        | PORT_PREFIX
+        | INTERCHAIN_ACCOUNT_PREFIX
+        | CAPABILITY_PREFIX
+        | CLIENT_PARAMS
+        | CONNECTION_PARAMS
+        | CHANNEL_PARAMS => (&[0], false),
+        UPGRADED_IBC_STATE => (&[0], true),
+        _ => (&[], true),
+    };
+
+    let len = components.len();
+
+    positions
+        .iter()
+        .copied()
+        .filter(move |&index| index < len)
+        .chain(len.checked_sub(1).filter(|_| leaf))
+}
+
+/// Returns the constant component which equals the given one up to case, if
+/// any, and the component itself otherwise.
+fn normalize_keyword(component: &str) -> &str {
+    KEYWORDS
+        .iter()
+        .find(|keyword| keyword.eq_ignore_ascii_case(component))
+        .unwrap_or(&component)
+}
+
+fn expect_component_count(components: &[&str], expected: usize) -> Result<(), PathError> {
+    if components.len() != expected {
+        return Err(PathError::WrongComponentCount {
+            expected,
+            found: components.len(),
+        });
+    }
+
+    Ok(())
+}
+
+fn expect_component(component: &str, expected: &str) -> Result<(), PathError> {
+    if component != expected {
+        return Err(unexpected_component(component));
+    }
+
+    Ok(())
+}
+
+fn unexpected_component(component: &str) -> PathError {
+    PathError::UnexpectedComponent {
+        component: component.into(),
+    }
+}
+
+/// An identifier embedded in paths, which maps its parse failures to the
+/// dedicated [`PathError`] variant, if any.
+trait PathIdentifier: FromStr<Err = IdentifierError> {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError;
+}
+
+impl PathIdentifier for ClientId {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError {
+        PathError::InvalidClientId { component, source }
+    }
+}
+
+impl PathIdentifier for ConnectionId {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError {
+        PathError::InvalidConnectionId { component, source }
+    }
+}
+
+impl PathIdentifier for PortId {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError {
+        PathError::InvalidPortId { component, source }
+    }
+}
+
+impl PathIdentifier for ChannelId {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError {
+        PathError::InvalidChannelId { component, source }
+    }
+}
+
+impl PathIdentifier for Sequence {
+    fn invalid(component: PathFragment, source: IdentifierError) -> PathError {
+        PathError::InvalidSequence { component, source }
+    }
+}
+
+/// An identifier whose validation is delegated to an [`IdValidator`] when
+/// parsed from a path.
+trait ValidatedIdentifier: PathIdentifier + UncheckedIdentifier {
+    fn validate(ids: &dyn IdValidator, id: &str) -> Result<(), IdentifierError>;
+}
+
+impl ValidatedIdentifier for ClientId {
+    fn validate(ids: &dyn IdValidator, id: &str) -> Result<(), IdentifierError> {
+        ids.validate_client_id(id)
+    }
+}
+
+impl ValidatedIdentifier for ConnectionId {
+    fn validate(ids: &dyn IdValidator, id: &str) -> Result<(), IdentifierError> {
+        ids.validate_connection_id(id)
+    }
+}
+
+impl ValidatedIdentifier for PortId {
+    fn validate(ids: &dyn IdValidator, id: &str) -> Result<(), IdentifierError> {
+        ids.validate_port_id(id)
+    }
+}
+
+impl ValidatedIdentifier for ChannelId {
+    fn validate(ids: &dyn IdValidator, id: &str) -> Result<(), IdentifierError> {
+        ids.validate_channel_id(id)
+    }
+}
+
+fn parse_identifier<T: PathIdentifier>(component: &str) -> Result<T, PathError> {
+    T::from_str(component).map_err(|source| T::invalid(component.into(), source))
+}
+
+fn parse_identifier_with<T: ValidatedIdentifier>(
+    component: &str,
+    ids: &dyn IdValidator,
+) -> Result<T, PathError> {
+    T::validate(ids, component).map_err(|source| T::invalid(component.into(), source))?;
+
+    Ok(T::new_unchecked(component))
+}
+
+fn parse_height(component: &str) -> Result<u64, PathError> {
+    if !is_canonical_decimal(component) {
+        return Err(PathError::InvalidHeight {
+            height: component.into(),
+        });
+    }
+
+    component
+        .parse()
+        .map_err(|e: ParseIntError| match e.kind() {
+            IntErrorKind::PosOverflow => PathError::HeightOverflow {
+                height: component.into(),
+            },
+            _ => PathError::InvalidHeight {
+                height: component.into(),
+            },
+        })
+}
+
+fn parse_next_sequence(components: &[&str]) -> Result<Path, PathError> {
+    expect_component_count(components, 1)?;
+
+    match components[0] {
+        NEXT_CLIENT_SEQUENCE => Ok(NextClientSequencePath.into()),
+        NEXT_CONNECTION_SEQUENCE => Ok(NextConnectionSequencePath.into()),
+        NEXT_CHANNEL_SEQUENCE => Ok(NextChannelSequencePath.into()),
+        prefix => Err(PathError::UnknownPrefix {
+            prefix: prefix.into(),
+        }),
+    }
+}
+
+/// Returns the length of the given components joined by `/`.
+fn joined_len(lens: &[usize]) -> usize {
+    lens.iter().sum::<usize>() + lens.len().saturating_sub(1)
+}
+
+/// Returns the number of decimal digits of the given number.
+fn decimal_len(n: u64) -> usize {
+    n.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
+
+/// Returns the length of a height in the "{revision_number}-{revision_height}"
+/// form.
+fn revision_len(revision_number: u64, revision_height: u64) -> usize {
+    decimal_len(revision_number) + 1 + decimal_len(revision_height)
+}
+
+/// Parses a height in the "{revision_number}-{revision_height}" form.
+fn parse_revision(component: &str) -> Result<(u64, u64), PathError> {
+    let (revision_number, revision_height) =
+        component
+            .split_once('-')
+            .ok_or_else(|| PathError::InvalidHeight {
+                height: component.into(),
+            })?;
+
+    Ok((
+        parse_height(revision_number)?,
+        parse_height(revision_height)?,
+    ))
+}
+
+fn parse_client_paths(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    // Client paths consist of 3 to 5 components.
+    if !(3..=5).contains(&components.len()) {
+        return Err(PathError::WrongComponentCount {
+            expected: components.len().clamp(3, 5),
+            found: components.len(),
+        });
+    }
+
+    expect_component(components[0], CLIENT_PREFIX)?;
+
+    let client_id = parse_identifier_with(components[1], ids)?;
+
+    if components.len() == 3 {
+        return match components[2] {
+            CLIENT_STATE => Ok(ClientStatePath(client_id).into()),
+            CLIENT_TYPE => Ok(ClientTypePath(client_id).into()),
+            CONNECTION_PREFIX => Ok(ClientConnectionPath(client_id).into()),
+            CONSENSUS_STATE_PREFIX => Ok(ClientConsensusStatePrefixPath(client_id).into()),
+            component => Err(unexpected_component(component)),
+        };
+    }
+
+    expect_component(components[2], CONSENSUS_STATE_PREFIX)?;
+
+    let (revision_number, revision_height) = parse_revision(components[3])?;
+
+    match components.get(4) {
+        None => Ok(ClientConsensusStatePath {
+            client_id,
+            revision_number,
+            revision_height,
+        }
+        .into()),
+        Some(&PROCESSED_TIME) => Ok(ClientUpdateTimePath {
+            client_id,
+            revision_number,
+            revision_height,
+        }
+        .into()),
+        Some(&PROCESSED_HEIGHT) => Ok(ClientUpdateHeightPath {
+            client_id,
+            revision_number,
+            revision_height,
+        }
+        .into()),
+        Some(component) => Err(unexpected_component(component)),
+    }
+}
+
+fn parse_connections(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 2)?;
+
+    expect_component(components[0], CONNECTION_PREFIX)?;
+
+    let connection_id = parse_identifier_with(components[1], ids)?;
+
+    Ok(ConnectionPath(connection_id).into())
+}
+
+fn parse_ports(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 2)?;
+
+    expect_component(components[0], PORT_PREFIX)?;
+
+    let port_id = parse_identifier_with(components[1], ids)?;
+
+    Ok(PortPath(port_id).into())
+}
+
+/// Parses the `channels/{channel_id}` path fragment.
+///
+/// Together with [`parse_sequences`], this allows composing parsers for keys
+/// which are not part of the specification:
+///
+/// ```
+/// use ibc_core_host_types::identifiers::{ChannelId, Sequence};
+/// use ibc_core_host_types::path::{parse_channels, parse_sequences, SubPath};
+///
+/// let components: Vec<&str> = "vendor/channels/channel-0/sequences/5".split('/').collect();
+///
+/// let SubPath::Channels(channel_id) = parse_channels(&components[1..3]).unwrap() else {
+///     panic!("expected a channel");
+/// };
+/// let SubPath::Sequences(sequence) = parse_sequences(&components[3..]).unwrap() else {
+///     panic!("expected a sequence");
+/// };
+///
+/// assert_eq!(channel_id, ChannelId::zero());
+/// assert_eq!(sequence, Sequence::from(5));
+/// ```
+pub fn parse_channels(components: &[&str]) -> Result<SubPath, PathError> {
+    parse_channels_with(components, &StrictIdValidator)
+}
+
+fn parse_channels_with(components: &[&str], ids: &dyn IdValidator) -> Result<SubPath, PathError> {
+    expect_component_count(components, 2)?;
+
+    expect_component(components[0], CHANNEL_PREFIX)?;
+
+    let channel_id = parse_identifier_with(components[1], ids)?;
+
+    Ok(SubPath::Channels(channel_id))
+}
+
+/// Parses the `sequences/{sequence}` path fragment. See [`parse_channels`] for
+/// an example of composing fragment parsers.
+pub fn parse_sequences(components: &[&str]) -> Result<SubPath, PathError> {
+    expect_component_count(components, 2)?;
+
+    expect_component(components[0], SEQUENCE_PREFIX)?;
+
+    let sequence = parse_sequence(components[1])?;
+
+    Ok(SubPath::Sequences(sequence))
+}
+
+fn parse_sequence(component: &str) -> Result<Sequence, PathError> {
+    if !is_canonical_decimal(component) {
+        return Err(Sequence::invalid(
+            component.into(),
+            IdentifierError::FailedToParse {
+                description: format!("sequence `{component}` has a sign or leading zeros"),
+            },
+        ));
+    }
+
+    parse_identifier(component)
+}
+
+/// Parses the `ports/{port_id}` segment embedded in the channel-scoped paths.
+/// Unlike [`parse_ports`], which is only dispatched to for keys starting with
+/// "ports", a corrupted "ports" literal is reported as such.
+fn parse_port_segment(components: &[&str], ids: &dyn IdValidator) -> Result<PortId, PathError> {
+    expect_component_count(components, 2)?;
+
+    if components[0] != PORT_PREFIX {
+        return Err(PathError::MissingPortSegment {
+            component: components[0].into(),
+        });
+    }
+
+    parse_identifier_with(components[1], ids)
+}
+
+/// Parses the `ports/{port_id}/channels/{channel_id}` components shared by all
+/// the channel-scoped paths.
+fn parse_port_and_channel(
+    components: &[&str],
+    ids: &dyn IdValidator,
+) -> Result<(PortId, ChannelId), PathError> {
+    let port_id = parse_port_segment(&components[..2], ids)?;
+
+    let SubPath::Channels(channel_id) = parse_channels_with(&components[2..], ids)? else {
+        return Err(unexpected_component(components[2]));
+    };
+
+    Ok((port_id, channel_id))
+}
+
+/// Parses the `ports/{port_id}/channels/{channel_id}/sequences/{sequence}`
+/// components shared by all the packet paths.
+fn parse_packet(
+    components: &[&str],
+    ids: &dyn IdValidator,
+) -> Result<(PortId, ChannelId, Sequence), PathError> {
+    let (port_id, channel_id) = parse_port_and_channel(&components[..4], ids)?;
+
+    let SubPath::Sequences(sequence) = parse_sequences(&components[4..])? else {
+        return Err(unexpected_component(components[4]));
+    };
+
+    Ok((port_id, channel_id, sequence))
+}
+
+fn parse_channel_ends(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 5)?;
+
+    expect_component(components[0], CHANNEL_END_PREFIX)?;
+
+    let (port_id, channel_id) = parse_port_and_channel(&components[1..], ids)?;
+
+    Ok(ChannelEndPath(port_id, channel_id).into())
+}
+
+fn parse_seqs(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 5)?;
+
+    let seq_path: fn(PortId, ChannelId) -> Path = match components[0] {
+        NEXT_SEQ_SEND_PREFIX => |port_id, channel_id| SeqSendPath(port_id, channel_id).into(),
+        NEXT_SEQ_RECV_PREFIX => |port_id, channel_id| SeqRecvPath(port_id, channel_id).into(),
+        NEXT_SEQ_ACK_PREFIX => |port_id, channel_id| SeqAckPath(port_id, channel_id).into(),
+        prefix => {
+            return Err(PathError::UnknownPrefix {
+                prefix: prefix.into(),
+            })
+        }
+    };
+
+    let (port_id, channel_id) = parse_port_and_channel(&components[1..], ids)?;
+
+    Ok(seq_path(port_id, channel_id))
+}
+
+fn parse_commitments(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 7)?;
+
+    expect_component(components[0], PACKET_COMMITMENT_PREFIX)?;
+
+    let (port_id, channel_id, sequence) = parse_packet(&components[1..], ids)?;
+
+    Ok(CommitmentPath {
+        port_id,
+        channel_id,
+        sequence,
+    }
+    .into())
+}
+
+fn parse_acks(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 7)?;
+
+    expect_component(components[0], PACKET_ACK_PREFIX)?;
+
+    let (port_id, channel_id, sequence) = parse_packet(&components[1..], ids)?;
+
+    Ok(AckPath {
+        port_id,
+        channel_id,
+        sequence,
+    }
+    .into())
+}
+
+fn parse_receipts(components: &[&str], ids: &dyn IdValidator) -> Result<Path, PathError> {
+    expect_component_count(components, 7)?;
+
+    expect_component(components[0], PACKET_RECEIPT_PREFIX)?;
+
+    let (port_id, channel_id, sequence) = parse_packet(&components[1..], ids)?;
+
+    Ok(ReceiptPath {
+        port_id,
+        channel_id,
+        sequence,
+    }
+    .into())
+}
+
+fn parse_upgrade_client_state(components: &[&str]) -> Result<Path, PathError> {
+    let (upgrade_path, height) = parse_upgrade_path(components, UPGRADED_CLIENT_STATE)?;
+
+    Ok(UpgradeClientStatePath {
+        upgrade_path,
+        height,
+    }
+    .into())
+}
+
+fn parse_upgrade_consensus_state(components: &[&str]) -> Result<Path, PathError> {
+    let (upgrade_path, height) = parse_upgrade_path(components, UPGRADED_CLIENT_CONSENSUS_STATE)?;
+
+    Ok(UpgradeConsensusStatePath {
+        upgrade_path,
+        height,
+    }
+    .into())
+}
+
+/// Parses the components of an upgrade path, i.e. the upgrade path root, which
+/// may consist of several components, followed by the height and the leaf.
+fn parse_upgrade_path(components: &[&str], leaf: &str) -> Result<(String, u64), PathError> {
+    let [upgrade_path @ .., height, component] = components else {
+        return Err(PathError::WrongComponentCount {
+            expected: 3,
+            found: components.len(),
+        });
+    };
+
+    if upgrade_path.is_empty() {
+        return Err(PathError::WrongComponentCount {
+            expected: 3,
+            found: components.len(),
+        });
+    }
+
+    expect_component(component, leaf)?;
+
+    Ok((upgrade_path.join("/"), parse_height(height)?))
+}
+
+fn parse_interchain_accounts(
+    components: &[&str],
+    ids: &dyn IdValidator,
+) -> Result<Path, PathError> {
+    expect_component_count(components, 3)?;
+
+    expect_component(components[0], INTERCHAIN_ACCOUNT_PREFIX)?;
+
+    let connection_id = parse_identifier_with(components[1], ids)?;
+
+    Ok(InterchainAccountPath {
+        connection_id,
+        owner: components[2].to_string(),
+    }
+    .into())
+}
+
+fn parse_capabilities(components: &[&str]) -> Result<Path, PathError> {
+    expect_component_count(components, 2)?;
+
+    expect_component(components[0], CAPABILITY_PREFIX)?;
+
+    let invalid = || PathError::InvalidCapabilityIndex {
+        index: components[1].into(),
+    };
+
+    if !is_canonical_decimal(components[1]) {
+        return Err(invalid());
+    }
+
+    let index = components[1].parse().map_err(|_| invalid())?;
+
+    Ok(CapabilityPath(index).into())
+}
+
+fn parse_params(components: &[&str]) -> Result<Path, PathError> {
+    expect_component_count(components, 1)?;
+
+    match components[0] {
+        CLIENT_PARAMS => Ok(ParamsPath::Client.into()),
+        CONNECTION_PARAMS => Ok(ParamsPath::Connection.into()),
+        CHANNEL_PARAMS => Ok(ParamsPath::Channel.into()),
+        prefix => Err(PathError::UnknownPrefix {
+            prefix: prefix.into(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const DEFAULT_CLIENT_ID_STR: &str = "07-tendermint-0";
+    impl ClientId {
+        pub fn new_dummy() -> Self {
+            ClientId::from_str(DEFAULT_CLIENT_ID_STR)
+                .expect("should not fail since we use a valid client id")
+        }
+    }
+
+    /// Returns a sample path of every variant, along with its expected string
+    /// representation.
+    fn sample_paths() -> Vec<(&'static str, Path)> {
+        vec![
+            (
+                NEXT_CLIENT_SEQUENCE,
+                Path::NextClientSequence(NextClientSequencePath),
+            ),
+            (
+                NEXT_CONNECTION_SEQUENCE,
+                Path::NextConnectionSequence(NextConnectionSequencePath),
+            ),
+            (
+                NEXT_CHANNEL_SEQUENCE,
+                Path::NextChannelSequence(NextChannelSequencePath),
+            ),
+            (
+                "clients/07-tendermint-0/clientState",
+                Path::ClientState(ClientStatePath(ClientId::new_dummy())),
+            ),
+            (
+                "clients/07-tendermint-0/clientType",
+                Path::ClientType(ClientTypePath(ClientId::new_dummy())),
+            ),
+            (
+                "clients/07-tendermint-0/consensusStates/15-31",
+                Path::ClientConsensusState(ClientConsensusStatePath {
+                    client_id: ClientId::new_dummy(),
+                    revision_number: 15,
+                    revision_height: 31,
+                }),
+            ),
+            (
+                "clients/07-tendermint-0/consensusStates/15-31/processedTime",
+                Path::ClientUpdateTime(ClientUpdateTimePath {
+                    client_id: ClientId::new_dummy(),
+                    revision_number: 15,
+                    revision_height: 31,
+                }),
+            ),
+            (
+                "clients/07-tendermint-0/consensusStates/15-31/processedHeight",
+                Path::ClientUpdateHeight(ClientUpdateHeightPath {
+                    client_id: ClientId::new_dummy(),
+                    revision_number: 15,
+                    revision_height: 31,
+                }),
+            ),
+            (
+                "clients/07-tendermint-0/connections",
+                Path::ClientConnection(ClientConnectionPath(ClientId::new_dummy())),
+            ),
+            (
+                "connections/connection-0",
+                Path::Connection(ConnectionPath(ConnectionId::zero())),
+            ),
+            ("ports/transfer", Path::Ports(PortPath(PortId::transfer()))),
+            (
+                "channelEnds/ports/transfer/channels/channel-0",
+                Path::ChannelEnd(ChannelEndPath(PortId::transfer(), ChannelId::zero())),
+            ),
+            (
+                "nextSequenceSend/ports/transfer/channels/channel-0",
+                Path::SeqSend(SeqSendPath(PortId::transfer(), ChannelId::zero())),
+            ),
+            (
+                "nextSequenceRecv/ports/transfer/channels/channel-0",
+                Path::SeqRecv(SeqRecvPath(PortId::transfer(), ChannelId::zero())),
+            ),
+            (
+                "nextSequenceAck/ports/transfer/channels/channel-0",
+                Path::SeqAck(SeqAckPath(PortId::transfer(), ChannelId::zero())),
+            ),
+            (
+                "commitments/ports/transfer/channels/channel-0/sequences/0",
+                Path::Commitment(CommitmentPath {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::zero(),
+                    sequence: Sequence::from(0),
+                }),
+            ),
+            (
+                "acks/ports/transfer/channels/channel-0/sequences/0",
+                Path::Ack(AckPath {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::zero(),
+                    sequence: Sequence::from(0),
+                }),
+            ),
+            (
+                "receipts/ports/transfer/channels/channel-0/sequences/0",
+                Path::Receipt(ReceiptPath {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::zero(),
+                    sequence: Sequence::from(0),
+                }),
+            ),
+            (
+                "upgradedIBCState/0/upgradedClient",
+                Path::UpgradeClientState(UpgradeClientStatePath {
+                    upgrade_path: UPGRADED_IBC_STATE.to_string(),
+                    height: 0,
+                }),
+            ),
+            (
+                "upgradedIBCState/0/upgradedConsState",
+                Path::UpgradeConsensusState(UpgradeConsensusStatePath {
+                    upgrade_path: UPGRADED_IBC_STATE.to_string(),
+                    height: 0,
+                }),
+            ),
+            (
+                "interchainAccounts/connection-0/cosmos1owner",
+                Path::InterchainAccount(InterchainAccountPath {
+                    connection_id: ConnectionId::zero(),
+                    owner: "cosmos1owner".to_string(),
+                }),
+            ),
+            ("capabilities/1", Path::Capability(CapabilityPath(1))),
+            ("clientParams", Path::Params(ParamsPath::Client)),
+            (
+                "clients/07-tendermint-0/consensusStates",
+                Path::ClientConsensusStatePrefix(ClientConsensusStatePrefixPath(
+                    ClientId::new_dummy(),
+                )),
+            ),
+        ]
+    }
+
+    /// Returns a path of every variant, parsed from dummy data.
+    fn dummy_paths() -> Vec<Path> {
+        sample_paths().into_iter().map(|(_, path)| path).collect()
+    }
+
+    #[test]
+    fn test_successful_parsing() {
+        let mut sampled = [false; 24];
+
+        for (path_str, path) in sample_paths() {
+            // can be parsed into Path
+            assert_eq!(Path::from_str(path_str).expect("no error"), path);
+            // can be converted back to string
+            assert_eq!(path_str, path.to_string());
+
+            // The match is exhaustive, so that adding a variant requires adding
+            // a sample of it as well.
+            let index = match path {
+                Path::NextClientSequence(_) => 0,
+                Path::NextConnectionSequence(_) => 1,
+                Path::NextChannelSequence(_) => 2,
+                Path::ClientState(_) => 3,
+                Path::ClientConsensusState(_) => 4,
+                Path::ClientUpdateTime(_) => 5,
+                Path::ClientUpdateHeight(_) => 6,
+                Path::ClientConnection(_) => 7,
+                Path::Connection(_) => 8,
+                Path::Ports(_) => 9,
+                Path::ChannelEnd(_) => 10,
+                Path::SeqSend(_) => 11,
+                Path::SeqRecv(_) => 12,
+                Path::SeqAck(_) => 13,
+                Path::Commitment(_) => 14,
+                Path::Ack(_) => 15,
+                Path::Receipt(_) => 16,
+                Path::UpgradeClientState(_) => 17,
+                Path::UpgradeConsensusState(_) => 18,
+                Path::InterchainAccount(_) => 19,
+                Path::Capability(_) => 20,
+                Path::Params(_) => 21,
+                Path::ClientConsensusStatePrefix(_) => 22,
+                Path::ClientType(_) => 23,
+            };
+            sampled[index] = true;
+        }
+
+        assert!(sampled.iter().all(|sampled| *sampled));
+    }
+
+    #[test]
+    fn test_client_consensus_state_prefix_path() {
+        let path = Path::ClientConsensusStatePrefix(ClientConsensusStatePrefixPath::new(
+            ClientId::new_dummy(),
+        ));
+
+        assert_eq!(
+            path.to_string(),
+            full_consensus_state_path(&ClientId::new_dummy())
+        );
+        assert_eq!(Path::from_str(&path.to_string()).expect("no error"), path);
+
+        for (revision_number, revision_height) in [(0, 1), (1, 0), (15, 31), (u64::MAX, u64::MAX)] {
+            let consensus_path = Path::ClientConsensusState(ClientConsensusStatePath::new(
+                ClientId::new_dummy(),
+                revision_number,
+                revision_height,
+            ));
+
+            assert!(consensus_path.is_under(&path.to_string()));
+            assert_eq!(
+                path.store_key_cmp(&consensus_path),
+                core::cmp::Ordering::Less
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/consensusStates/0-100")]
+    #[case("clients/07-tendermint-0/consensusStates/0-100/processedTime")]
+    #[case("clients/07-tendermint-0/consensusStates/0-100/processedHeight")]
+    #[case("clients/07-tendermint-0/consensusStates/0-0")]
+    fn test_zero_revision_paths_roundtrip(#[case] path_str: &str) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.to_string(), path_str);
+    }
+
+    #[rstest::rstest]
+    #[case("15-31", Some((15, 31)))]
+    #[case("0-1", Some((0, 1)))]
+    #[case("1531", None)]
+    #[case("15-", None)]
+    #[case("-31", None)]
+    #[case("15-31-1", None)]
+    #[case("a-31", None)]
+    fn test_client_consensus_state_path_from_parts(
+        #[case] revision: &str,
+        #[case] expected: Option<(u64, u64)>,
+    ) {
+        let path = ClientConsensusStatePath::from_parts(ClientId::new_dummy(), revision);
+
+        match expected {
+            Some((revision_number, revision_height)) => assert_eq!(
+                path.expect("no error"),
+                ClientConsensusStatePath::new(
+                    ClientId::new_dummy(),
+                    revision_number,
+                    revision_height
+                )
+            ),
+            None => assert!(matches!(path, Err(PathError::InvalidHeight { .. }))),
+        }
+    }
+
+    #[test]
+    fn test_borrowing_constructors() {
+        let port_id = PortId::transfer();
+        assert_eq!(PortPath::new(&port_id), PortPath(port_id));
+
+        let client_id = ClientId::new_dummy();
+        assert_eq!(
+            ClientConsensusStatePath::new_ref(&client_id, 1, 2),
+            ClientConsensusStatePath {
+                client_id,
+                revision_number: 1,
+                revision_height: 2,
+            }
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(&[])]
+    #[case(&["connection-0"])]
+    #[case(&["connection-0", "connection-1", "connection-42"])]
+    fn test_client_connections_round_trip(#[case] ids: &[&str]) {
+        let ids: Vec<ConnectionId> = ids
+            .iter()
+            .map(|id| ConnectionId::from_str(id).expect("no error"))
+            .collect();
+
+        let bytes = ClientConnectionPath::encode_connections(&ids);
+
+        assert_eq!(
+            ClientConnectionPath::decode_connections(&bytes).expect("no error"),
+            ids
+        );
+    }
+
+    #[test]
+    fn test_client_connections_encoding() {
+        let bytes = ClientConnectionPath::encode_connections(&[ConnectionId::zero()]);
+
+        assert_eq!(bytes, b"\x0a\x0cconnection-0");
+
+        // Truncated identifier.
+        assert!(matches!(
+            ClientConnectionPath::decode_connections(&bytes[..6]),
+            Err(PathError::InvalidClientConnections { .. })
+        ));
+        // Unexpected field tag.
+        assert!(matches!(
+            ClientConnectionPath::decode_connections(b"\x12\x0cconnection-0"),
+            Err(PathError::InvalidClientConnections { .. })
+        ));
+        assert!(matches!(
+            ClientConnectionPath::decode_connections(b"\x0a\x06conn-0"),
+            Err(PathError::InvalidConnectionId { component, .. }) if component == "conn-0"
+        ));
+    }
+
+    #[test]
+    fn test_seq_recv_path_for_both_orderings() {
+        // The key carries no ordering, so both orderings map to `SeqRecvPath`.
+        let path_str = "nextSequenceRecv/ports/transfer/channels/channel-0";
+
+        assert_eq!(
+            Path::from_str(path_str).expect("no error"),
+            Path::SeqRecv(SeqRecvPath::new(&PortId::transfer(), &ChannelId::zero()))
+        );
+
+        // The receipts of unordered channels live under a distinct prefix.
+        assert!(matches!(
+            Path::from_str("receipts/ports/transfer/channels/channel-0/sequences/1"),
+            Ok(Path::Receipt(_))
+        ));
+    }
+
+    #[test]
+    fn test_port_and_channel_path_accessors() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(7);
+
+        let path = ChannelEndPath::new(&port_id, &channel_id);
+        assert_eq!((path.port_id(), path.channel_id()), (&port_id, &channel_id));
+
+        let path = SeqSendPath::new(&port_id, &channel_id);
+        assert_eq!((path.port_id(), path.channel_id()), (&port_id, &channel_id));
+
+        let path = SeqRecvPath::new(&port_id, &channel_id);
+        assert_eq!((path.port_id(), path.channel_id()), (&port_id, &channel_id));
+
+        let path = SeqAckPath::new(&port_id, &channel_id);
+        assert_eq!((path.port_id(), path.channel_id()), (&port_id, &channel_id));
+    }
+
+    #[test]
+    fn test_packet_path_ranges() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::zero();
+
+        assert_eq!(
+            CommitmentPath::range(&port_id, &channel_id, 1..=3).collect::<Vec<_>>(),
+            (1..=3)
+                .map(|seq| CommitmentPath::new(&port_id, &channel_id, Sequence::from(seq)))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            AckPath::range(&port_id, &channel_id, 5..=5).collect::<Vec<_>>(),
+            vec![AckPath::new(&port_id, &channel_id, Sequence::from(5))]
+        );
+        assert_eq!(
+            ReceiptPath::range(&port_id, &channel_id, 0..=1)
+                .map(|path| path.sequence)
+                .collect::<Vec<_>>(),
+            vec![Sequence::from(0), Sequence::from(1)]
+        );
+    }
+
+    #[test]
+    fn test_channel_paths_by_ordering() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::zero();
+
+        let ordered = ordered_channel_paths(&port_id, &channel_id);
+        let unordered = unordered_channel_paths(&port_id, &channel_id);
+
+        for paths in [&ordered, &unordered] {
+            assert!(paths.contains(&ChannelEndPath::new(&port_id, &channel_id).into()));
+            assert!(paths.contains(&SeqSendPath::new(&port_id, &channel_id).into()));
+            assert!(paths
+                .iter()
+                .all(|path| path.channel_scope() == Some((&port_id, &channel_id))));
+            assert!(!paths.iter().any(|path| path.kind() == PathKind::Receipt));
+        }
+
+        assert!(ordered.contains(&SeqRecvPath::new(&port_id, &channel_id).into()));
+        assert!(ordered.contains(&SeqAckPath::new(&port_id, &channel_id).into()));
+        assert!(!unordered.contains(&SeqRecvPath::new(&port_id, &channel_id).into()));
+        assert!(!unordered.contains(&SeqAckPath::new(&port_id, &channel_id).into()));
+
+        assert_ne!(ordered, unordered);
+    }
+
+    #[test]
+    fn test_packet_key() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(2);
+        let sequence = Sequence::from(9);
+
+        let key = PacketKey::from(&CommitmentPath::new(&port_id, &channel_id, sequence));
+
+        assert_eq!(
+            key,
+            PacketKey::from(&AckPath::new(&port_id, &channel_id, sequence))
+        );
+        assert_eq!(
+            key,
+            PacketKey::from(&ReceiptPath::new(&port_id, &channel_id, sequence))
+        );
+        assert_eq!(
+            (&key.port_id, &key.channel_id, key.sequence),
+            (&port_id, &channel_id, sequence)
+        );
+
+        assert!(key < PacketKey::from(&AckPath::new(&port_id, &channel_id, 10.into())));
+        assert_ne!(
+            key,
+            PacketKey::from(&AckPath::new(&port_id, &ChannelId::new(3), sequence))
+        );
+    }
+
+    #[test]
+    fn test_receipt_path_sequences_roundtrip() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(3);
+        let sequences = [1, 2, 5, 8, 13].map(Sequence::from);
+
+        let paths = ReceiptPath::from_sequences(&port_id, &channel_id, &sequences);
+
+        assert_eq!(paths.len(), sequences.len());
+        assert_eq!(
+            paths[2],
+            ReceiptPath::new(&port_id, &channel_id, Sequence::from(5))
+        );
+        assert_eq!(ReceiptPath::collect_sequences(&paths), sequences);
+    }
+
+    #[test]
+    fn test_sub_path_from_borrowed_ids() {
+        let client_ids = [
+            ClientId::new_dummy(),
+            ClientId::new("08-wasm", 1).expect("no error"),
+        ];
+
+        assert_eq!(
+            client_ids
+                .iter()
+                .map(ClientStatePath::from)
+                .collect::<Vec<_>>(),
+            client_ids
+                .iter()
+                .cloned()
+                .map(ClientStatePath::new)
+                .collect::<Vec<_>>()
+        );
+
+        let connection_id = ConnectionId::new(3);
+
+        assert_eq!(
+            ConnectionPath::from(&connection_id),
+            ConnectionPath::new(&connection_id)
+        );
+        assert_eq!(
+            PortPath::from(&PortId::transfer()),
+            PortPath::new(&PortId::transfer())
+        );
+    }
+
+    #[test]
+    fn test_sub_path_from_seq() {
+        assert_eq!(
+            ConnectionPath::from_seq(7),
+            ConnectionPath::from_str("connections/connection-7").expect("no error")
+        );
+        assert_eq!(
+            ChannelEndPath::from_seqs(&PortId::transfer(), 12),
+            ChannelEndPath::from_str("channelEnds/ports/transfer/channels/channel-12")
+                .expect("no error")
+        );
+    }
+
+    #[test]
+    fn test_sub_path_from_str() {
+        let path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 7.into());
+
+        assert_eq!(
+            CommitmentPath::from_str(&path.to_string()).expect("no error"),
+            path
+        );
+
+        let path = ClientStatePath::new(ClientId::new_dummy());
+
+        assert_eq!(
+            ClientStatePath::from_str(&path.to_string()).expect("no error"),
+            path
+        );
+
+        assert!(matches!(
+            AckPath::from_str(&path.to_string()),
+            Err(PathError::WrongVariant {
+                expected: PathKind::Ack,
+                found: PathKind::ClientState,
+            })
+        ));
+        assert!(ChannelEndPath::from_str("channelEnds/ports/transfer").is_err());
+    }
+
+    #[test]
+    fn test_path_into_string() {
+        for path in dummy_paths() {
+            let expected = path.to_string();
+
+            let path = path.into_string();
+
+            assert_eq!(path, expected);
+        }
+    }
+
+    #[test]
+    fn test_path_encoded_len() {
+        for path in dummy_paths() {
+            assert_eq!(
+                path.encoded_len(),
+                path.clone().into_bytes().len(),
+                "{path}"
+            );
+        }
+
+        for n in [0, 1, 9, 10, 99, 100, u64::MAX] {
+            assert_eq!(decimal_len(n), n.to_string().len());
+        }
+
+        let path = Path::UpgradeConsensusState(UpgradeConsensusStatePath::new(
+            "upgrade/upgradedIBCState".to_string(),
+            u64::MAX,
+        ));
+
+        assert_eq!(path.encoded_len(), path.to_string().len());
+    }
+
+    #[test]
+    fn test_path_store_key_cmp() {
+        let next_client_sequence = Path::NextClientSequence(NextClientSequencePath);
+        let client_state = Path::ClientState(ClientStatePath(ClientId::new_dummy()));
+
+        assert_eq!(
+            next_client_sequence.cmp(&client_state),
+            core::cmp::Ordering::Less
+        );
+        assert_eq!(
+            next_client_sequence.store_key_cmp(&client_state),
+            core::cmp::Ordering::Greater
+        );
+
+        let mut paths = dummy_paths();
+        paths.sort_by(Path::store_key_cmp);
+
+        for pair in paths.windows(2) {
+            assert!(pair[0].to_string().as_bytes() <= pair[1].to_string().as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_byte_ordered_path() {
+        let by_variant: BTreeMap<Path, ()> = dummy_paths().into_iter().map(|p| (p, ())).collect();
+        let by_bytes: BTreeMap<ByteOrderedPath, ()> = dummy_paths()
+            .into_iter()
+            .map(|p| (ByteOrderedPath(p), ()))
+            .collect();
+
+        let by_variant: Vec<String> = by_variant.keys().map(ToString::to_string).collect();
+        let by_bytes: Vec<String> = by_bytes.keys().map(ToString::to_string).collect();
+
+        let mut expected = by_variant.clone();
+        expected.sort();
+
+        assert_eq!(by_bytes, expected);
+        assert_ne!(by_variant, expected);
+    }
+
+    #[cfg(feature = "key-encoding")]
+    #[test]
+    fn test_path_key_encodings() {
+        let path = Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+
+        assert_eq!(
+            path.to_hex(),
+            "636f6d6d69746d656e74732f706f7274732f7472616e736665722f6368616e6e656c732f6368616e6e656c2d302f73657175656e6365732f31"
+        );
+        assert_eq!(
+            path.to_base64(),
+            "Y29tbWl0bWVudHMvcG9ydHMvdHJhbnNmZXIvY2hhbm5lbHMvY2hhbm5lbC0wL3NlcXVlbmNlcy8x"
+        );
+    }
+
+    #[test]
+    fn test_path_abci_query() {
+        let path = Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+
+        assert_eq!(path.abci_query_path(), "store/ibc/key");
+        assert_eq!(
+            path.abci_query_data(),
+            b"commitments/ports/transfer/channels/channel-0/sequences/1"
+        );
+
+        let path = Path::upgraded_client_state(1);
+
+        assert_eq!(path.abci_query_path(), "store/upgrade/key");
+        assert_eq!(path.abci_query_data(), b"upgradedIBCState/1/upgradedClient");
+    }
+
+    #[test]
+    fn test_path_prefixed_key() {
+        let path = Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+
+        assert_eq!(
+            path.prefixed_key(b"ibc"),
+            vec![
+                b"ibc".to_vec(),
+                b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cached_path() {
+        for path in dummy_paths() {
+            let cached = CachedPath::from(path.clone());
+
+            assert_eq!(cached.as_str(), path.to_string());
+            assert_eq!(cached.to_string(), path.to_string());
+            assert_eq!(cached.kind(), path.kind());
+            // The second call returns the memoized string.
+            assert!(core::ptr::eq(cached.as_str(), cached.as_str()));
+            assert_eq!(cached.into_inner(), path);
+        }
+    }
+
+    #[test]
+    fn test_path_segment_write() {
+        fn render<P: PathSegmentWrite>(path: &P, buffer: &mut String) {
+            buffer.clear();
+            path.write_to(buffer).expect("no error");
+        }
+
+        let mut buffer = String::with_capacity(128);
+
+        let path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+        render(&path, &mut buffer);
+        assert_eq!(buffer, path.to_string());
+
+        render(&ParamsPath::Client, &mut buffer);
+        assert_eq!(buffer, CLIENT_PARAMS);
+
+        for path in dummy_paths() {
+            render(&path, &mut buffer);
+            assert_eq!(buffer, path.to_string());
+        }
+
+        assert_eq!(buffer.capacity(), 128);
+    }
+
+    #[test]
+    fn test_path_write_key() {
+        let mut buffer = String::new();
+
+        for path in dummy_paths() {
+            buffer.clear();
+            path.write_key(&mut buffer).expect("no error");
+
+            assert_eq!(buffer, path.to_string());
+            assert_eq!(buffer.as_bytes(), path.into_bytes());
+        }
+
+        let mut buffer = String::from("prefix/");
+        Path::Ports(PortPath(PortId::transfer()))
+            .write_key(&mut buffer)
+            .expect("no error");
+
+        assert_eq!(buffer, "prefix/ports/transfer");
+    }
+
+    #[test]
+    fn test_path_render_all() {
+        let paths = dummy_paths();
+        let mut buffer = String::new();
+
+        Path::render_all(&paths, &mut buffer);
+
+        let expected: String = paths.iter().map(|path| format!("{path}\n")).collect();
+
+        assert_eq!(buffer, expected);
+
+        // Keys are appended to the existing content of the buffer.
+        Path::render_all(&paths[..1], &mut buffer);
+
+        assert_eq!(buffer, format!("{expected}{}\n", paths[0]));
+    }
+
+    #[test]
+    fn test_path_prefix_constants_are_unique() {
+        let prefixes = [
+            NEXT_CLIENT_SEQUENCE,
+            NEXT_CONNECTION_SEQUENCE,
+            NEXT_CHANNEL_SEQUENCE,
+            CLIENT_PREFIX,
+            CLIENT_STATE,
+            CLIENT_TYPE,
+            CONSENSUS_STATE_PREFIX,
+            CONNECTION_PREFIX,
+            CHANNEL_PREFIX,
+            CHANNEL_END_PREFIX,
+            PORT_PREFIX,
+            SEQUENCE_PREFIX,
+            NEXT_SEQ_SEND_PREFIX,
+            NEXT_SEQ_RECV_PREFIX,
+            NEXT_SEQ_ACK_PREFIX,
+            PACKET_COMMITMENT_PREFIX,
+            PACKET_ACK_PREFIX,
+            PACKET_RECEIPT_PREFIX,
+            ITERATE_CONSENSUS_STATE_PREFIX,
+            PROCESSED_TIME,
+            PROCESSED_HEIGHT,
+            UPGRADED_IBC_STATE,
+            UPGRADED_CLIENT_STATE,
+            UPGRADED_CLIENT_CONSENSUS_STATE,
+            INTERCHAIN_ACCOUNT_PREFIX,
+            CAPABILITY_PREFIX,
+            CLIENT_PARAMS,
+            CONNECTION_PARAMS,
+            CHANNEL_PARAMS,
+        ];
+
+        for (i, prefix) in prefixes.iter().enumerate() {
+            assert!(!prefix.is_empty() && !prefix.contains('/'), "{prefix}");
+            assert!(!prefixes[i + 1..].contains(prefix), "duplicate {prefix}");
+        }
+    }
+
+    #[test]
+    fn test_path_from_str_lenient() {
+        for (i, keyword) in KEYWORDS.iter().enumerate() {
+            assert!(
+                !KEYWORDS[i + 1..]
+                    .iter()
+                    .any(|other| other.eq_ignore_ascii_case(keyword)),
+                "{keyword}"
+            );
+        }
+
+        for (path_str, path) in sample_paths() {
+            assert_eq!(Path::from_str_lenient(path_str).expect("no error"), path);
+        }
+    }
+
+    #[test]
+    fn test_path_from_str_lenient_keeps_identifiers() {
+        for (path_str, path) in [
+            (
+                "ports/Ports",
+                Path::Ports(PortPath(PortId::from_str("Ports").expect("no error"))),
+            ),
+            (
+                "channelends/ports/Channels/channels/channel-0",
+                Path::ChannelEnd(ChannelEndPath(
+                    PortId::from_str("Channels").expect("no error"),
+                    ChannelId::zero(),
+                )),
+            ),
+            (
+                "interchainaccounts/connection-0/Clients",
+                Path::InterchainAccount(InterchainAccountPath {
+                    connection_id: ConnectionId::zero(),
+                    owner: "Clients".to_string(),
+                }),
+            ),
+        ] {
+            let parsed = Path::from_str_lenient(path_str).expect("no error");
+
+            assert_eq!(parsed, path);
+            assert_eq!(parsed.to_string(), path.to_string());
+        }
+    }
+
+    #[test]
+    fn test_path_try_from_components() {
+        for (path_str, path) in sample_paths() {
+            let components: Vec<&str> = path_str.split('/').collect();
+
+            assert_eq!(
+                Path::try_from_components(&components).expect("no error"),
+                path
+            );
+        }
+
+        assert_eq!(
+            Path::try_from_components(&["ports", "transfer"]).expect("no error"),
+            Path::Ports(PortPath(PortId::transfer()))
+        );
+        assert!(matches!(
+            Path::try_from_components(&[]),
+            Err(PathError::EmptyComponent { index: 0 })
+        ));
+        assert!(matches!(
+            Path::try_from_components(&["clients", "", "clientState"]),
+            Err(PathError::EmptyComponent { index: 1 })
+        ));
+        assert!(matches!(
+            Path::try_from_components(&["interchainAccounts", "connection-0", "owner/0"]),
+            Err(PathError::UnexpectedComponent { component }) if component == "owner/0"
+        ));
+        assert!(matches!(
+            Path::try_from_components(&["capabilities"; 8]),
+            Err(PathError::TooManyComponents { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parsed_paths_are_canonical() {
+        let canonical = "clients/07-tendermint-0/clientState";
+        let path = Path::from_str(canonical).expect("no error");
+
+        for input in [
+            "/clients/07-tendermint-0/clientState",
+            "clients/07-tendermint-0/clientstate",
+            "/Clients/07-tendermint-0/CLIENTSTATE",
+        ] {
+            let parsed = Path::from_str_lenient(input).expect("no error");
+
+            assert_eq!(parsed, path);
+            assert_eq!(parsed.to_string(), canonical);
+        }
+
+        assert_ne!(
+            Path::from_str("clients/07-Tendermint-0/clientState").expect("no error"),
+            path
+        );
+    }
+
+    #[test]
+    fn test_path_canonical() {
+        for (_, path) in sample_paths() {
+            assert_eq!(path.clone().canonical(), path);
+        }
+
+        let hand_built = Path::UpgradeClientState(UpgradeClientStatePath {
+            upgrade_path: "/upgradedIBCState".to_string(),
+            height: 5,
+        });
+        let parsed = Path::from_str("upgradedIBCState/5/upgradedClient").expect("no error");
+
+        assert_ne!(hand_built, parsed);
+        assert!(hand_built.try_to_string().is_err());
+        assert_eq!(hand_built.canonical(), parsed);
+
+        let invalid = Path::Ports(PortPath(PortId::new_unchecked(
+            "transfer/channels/channel-1",
+        )));
+
+        assert_eq!(invalid.clone().canonical(), invalid);
+        assert!(matches!(
+            invalid.try_canonical(),
+            Err(PathError::WrongComponentCount {
+                expected: 2,
+                found: 4
+            })
+        ));
+        assert!(
+            Path::ClientState(ClientStatePath(ClientId::new_unchecked("07-tm-0")))
+                .try_canonical()
+                .is_err()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("nextclientsequence", NEXT_CLIENT_SEQUENCE)]
+    #[case("nextconnectionsequence", NEXT_CONNECTION_SEQUENCE)]
+    #[case("nextchannelsequence", NEXT_CHANNEL_SEQUENCE)]
+    #[case(
+        "clients/07-tendermint-0/clientstate",
+        "clients/07-tendermint-0/clientState"
+    )]
+    #[case(
+        "clients/07-tendermint-0/clienttype",
+        "clients/07-tendermint-0/clientType"
+    )]
+    #[case(
+        "clients/07-tendermint-0/consensusstates/1-2/processedtime",
+        "clients/07-tendermint-0/consensusStates/1-2/processedTime"
+    )]
+    #[case(
+        "clients/07-tendermint-0/consensusstates/1-2/processedheight",
+        "clients/07-tendermint-0/consensusStates/1-2/processedHeight"
+    )]
+    #[case(
+        "channelends/ports/transfer/channels/channel-0",
+        "channelEnds/ports/transfer/channels/channel-0"
+    )]
+    #[case(
+        "nextsequencesend/ports/transfer/channels/channel-0",
+        "nextSequenceSend/ports/transfer/channels/channel-0"
+    )]
+    #[case(
+        "nextsequencerecv/ports/transfer/channels/channel-0",
+        "nextSequenceRecv/ports/transfer/channels/channel-0"
+    )]
+    #[case(
+        "nextsequenceack/ports/transfer/channels/channel-0",
+        "nextSequenceAck/ports/transfer/channels/channel-0"
+    )]
+    #[case(
+        "upgradedibcstate/1/upgradedclient",
+        "upgradedIBCState/1/upgradedClient"
+    )]
+    #[case(
+        "upgradedibcstate/1/upgradedconsstate",
+        "upgradedIBCState/1/upgradedConsState"
+    )]
+    #[case(
+        "interchainaccounts/connection-0/owner",
+        "interchainAccounts/connection-0/owner"
+    )]
+    fn test_lowercased_path_from_str_lenient(#[case] path_str: &str, #[case] canonical: &str) {
+        assert!(Path::from_str(path_str)
+            .map(|path| path != canonical)
+            .unwrap_or(true));
+
+        let path = Path::from_str_lenient(path_str).expect("no error");
+
+        assert_eq!(path.to_string(), canonical);
+        assert_eq!(path, Path::from_str(canonical).expect("no error"));
+    }
+
+    #[test]
+    fn test_path_parse_many() {
+        let input = "nextClientSequence\n\nports/transfer\r\nports\n  \nclients/07-tendermint-0/clientState\n";
+
+        let results: Vec<_> = Path::parse_many(input).collect();
+
+        assert_eq!(
+            results.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            [0, 2, 3, 5]
+        );
+        assert!(matches!(results[0].1, Ok(Path::NextClientSequence(_))));
+        assert!(matches!(results[1].1, Ok(Path::Ports(_))));
+        assert!(matches!(
+            results[2].1,
+            Err(PathError::WrongComponentCount { .. })
+        ));
+        assert!(matches!(results[3].1, Ok(Path::ClientState(_))));
+    }
+
+    #[test]
+    fn test_path_eq_str() {
+        for (path_str, path) in sample_paths() {
+            assert_eq!(path, path_str);
+            let owned = format!("{path_str}/");
+            assert_eq!(path, owned[..path_str.len()]);
+            assert_ne!(path, owned);
+            assert!(path != path_str[..path_str.len() - 1]);
+        }
+
+        let path = Path::ClientState(ClientStatePath(ClientId::new_dummy()));
+
+        assert_eq!(path, "clients/07-tendermint-0/clientState");
+        assert_ne!(path, "clients/07-tendermint-1/clientState");
+        assert_ne!(path, "clients/07-tendermint-0/clientType");
+        assert_ne!(path, "");
+    }
+
+    #[test]
+    fn test_path_try_from_str() {
+        let path = Path::Ports(PortPath(PortId::transfer()));
+
+        assert_eq!(Path::try_from("ports/transfer").expect("no error"), path);
+
+        let paths = vec!["ports/transfer".to_string(), "ports".to_string()]
+            .into_iter()
+            .map(Path::try_from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(paths[0].as_ref().ok(), Some(&path));
+        assert!(matches!(
+            paths[1],
+            Err(PathError::WrongComponentCount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_path_from_bytes() {
+        let path = Path::Commitment(CommitmentPath {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            sequence: Sequence::from(0),
+        });
+
+        let bytes = path.clone().into_bytes();
+
+        assert_eq!(Path::from_bytes(&bytes).expect("no error"), path);
+        assert_eq!(Path::try_from(bytes.as_slice()).expect("no error"), path);
+
+        assert!(matches!(
+            Path::from_bytes(b"ports/\xff"),
+            Err(PathError::InvalidUtf8(_))
+        ));
+        assert!(matches!(
+            Path::from_bytes(b"ports"),
+            Err(PathError::WrongComponentCount { .. })
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", true)]
+    #[case("clients/07-tendermint-0/clientType", true)]
+    #[case("clients/07-tendermint-0/consensusStates/15-31", true)]
+    #[case("clients/07-tendermint-0/consensusStates/15-31/processedTime", true)]
+    #[case("clients/07-tendermint-0/consensusStates/15-31/processedHeight", true)]
+    #[case("clients/07-tendermint-0/connections", true)]
+    #[case(NEXT_CLIENT_SEQUENCE, false)]
+    #[case(NEXT_CONNECTION_SEQUENCE, false)]
+    #[case(NEXT_CHANNEL_SEQUENCE, false)]
+    #[case("connections/connection-0", false)]
+    #[case("ports/transfer", false)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", false)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", false)]
+    #[case("nextSequenceRecv/ports/transfer/channels/channel-0", false)]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", false)]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/0", false)]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/0", false)]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/0", false)]
+    #[case("upgradedIBCState/0/upgradedClient", false)]
+    #[case("upgradedIBCState/0/upgradedConsState", false)]
+    #[case("interchainAccounts/connection-0/owner", false)]
+    #[case("capabilities/1", false)]
+    fn test_path_client_id(#[case] path_str: &str, #[case] has_client_id: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let expected = has_client_id.then(ClientId::new_dummy);
+
+        assert_eq!(path.client_id(), expected.as_ref());
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", true)]
+    #[case("clients/07-tendermint-0/consensusStates/1-2/processedTime", true)]
+    #[case("connections/connection-0", false)]
+    #[case(NEXT_CLIENT_SEQUENCE, false)]
+    fn test_path_replace_client_id(#[case] path_str: &str, #[case] has_client_id: bool) {
+        let mut path = Path::from_str(path_str).expect("no error");
+        let new = ClientId::new("07-tendermint", 1).expect("no error");
+
+        assert_eq!(path.replace_client_id(new.clone()), has_client_id);
+
+        if has_client_id {
+            assert_eq!(path.client_id(), Some(&new));
+            assert_eq!(
+                path.to_string(),
+                path_str.replace("07-tendermint-0", "07-tendermint-1")
+            );
+        } else {
+            assert_eq!(path.to_string(), path_str);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("channelEnds/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", true)]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/1", true)]
+    #[case("channelEnds/ports/transfer/channels/channel-1", false)]
+    #[case("receipts/ports/transfer/channels/channel-1/sequences/1", false)]
+    #[case("ports/transfer", false)]
+    #[case(NEXT_CHANNEL_SEQUENCE, false)]
+    fn test_path_rewrite_channel(#[case] path_str: &str, #[case] expected: bool) {
+        let mut path = Path::from_str(path_str).expect("no error");
+        let rewritten = path.rewrite_channel(&ChannelId::zero(), ChannelId::new(7));
+
+        assert_eq!(rewritten, expected);
+
+        if rewritten {
+            assert_eq!(path.channel_id(), Some(&ChannelId::new(7)));
+            assert_eq!(path, path_str.replace("channel-0", "channel-7"));
+        } else {
+            assert_eq!(path, path_str);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("ports/transfer", true)]
+    #[case("nextSequenceRecv/ports/transfer/channels/channel-0", true)]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/1", true)]
+    #[case("channelEnds/ports/icahost/channels/channel-0", false)]
+    #[case("clients/07-tendermint-0/clientState", false)]
+    #[case(NEXT_CHANNEL_SEQUENCE, false)]
+    fn test_path_rewrite_port(#[case] path_str: &str, #[case] expected: bool) {
+        let mut path = Path::from_str(path_str).expect("no error");
+        let to = PortId::from_str("nft-transfer").expect("no error");
+        let rewritten = path.rewrite_port(&PortId::transfer(), to.clone());
+
+        assert_eq!(rewritten, expected);
+
+        if rewritten {
+            assert_eq!(path.port_id(), Some(&to));
+            assert_eq!(path, path_str.replace("transfer", "nft-transfer"));
+        } else {
+            assert_eq!(path, path_str);
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("channelEnds/ports/transfer/channels/channel-12", Some(12), None)]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/5", Some(0), None)]
+    #[case("connections/connection-3", None, Some(3))]
+    #[case("interchainAccounts/connection-3/owner", None, Some(3))]
+    #[case("ports/transfer", None, None)]
+    fn test_path_id_sequences(
+        #[case] path_str: &str,
+        #[case] channel_sequence: Option<u64>,
+        #[case] connection_sequence: Option<u64>,
+    ) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.channel_sequence(), channel_sequence);
+        assert_eq!(path.connection_sequence(), connection_sequence);
+    }
+
+    #[rstest::rstest]
+    #[case("connections/connection-0", true)]
+    #[case("interchainAccounts/connection-0/owner", true)]
+    #[case(NEXT_CONNECTION_SEQUENCE, false)]
+    #[case("clients/07-tendermint-0/connections", false)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", false)]
+    fn test_path_connection_id(#[case] path_str: &str, #[case] has_connection_id: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let expected = has_connection_id.then(ConnectionId::zero);
+
+        assert_eq!(path.connection_id(), expected.as_ref());
+    }
+
+    #[rstest::rstest]
+    #[case("ports/transfer", true, false)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", true, true)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", true, true)]
+    #[case("nextSequenceRecv/ports/transfer/channels/channel-0", true, true)]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", true, true)]
+    #[case(
+        "commitments/ports/transfer/channels/channel-0/sequences/0",
+        true,
+        true
+    )]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/0", true, true)]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/0", true, true)]
+    #[case(NEXT_CHANNEL_SEQUENCE, false, false)]
+    #[case("clients/07-tendermint-0/clientState", false, false)]
+    #[case("clients/07-tendermint-0/connections", false, false)]
+    #[case("connections/connection-0", false, false)]
+    #[case("upgradedIBCState/0/upgradedClient", false, false)]
+    fn test_path_port_and_channel_id(
+        #[case] path_str: &str,
+        #[case] has_port_id: bool,
+        #[case] has_channel_id: bool,
+    ) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let expected_port_id = has_port_id.then(PortId::transfer);
+        let expected_channel_id = has_channel_id.then(ChannelId::zero);
+
+        assert_eq!(path.port_id(), expected_port_id.as_ref());
+        assert_eq!(path.channel_id(), expected_channel_id.as_ref());
+    }
+
+    #[rstest::rstest]
+    #[case("channelEnds/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceRecv/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", true)]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/0", true)]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/0", true)]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/0", true)]
+    #[case("ports/transfer", false)]
+    #[case(NEXT_CHANNEL_SEQUENCE, false)]
+    #[case("capabilities/1", false)]
+    fn test_path_channel_scope(#[case] path_str: &str, #[case] has_channel_scope: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::zero();
+        let expected = has_channel_scope.then_some((&port_id, &channel_id));
+
+        assert_eq!(path.channel_scope(), expected);
+
+        for path in dummy_paths() {
+            if let Some((port_id, channel_id)) = path.channel_scope() {
+                assert_eq!(path.port_id(), Some(port_id));
+                assert_eq!(path.channel_id(), Some(channel_id));
+            } else {
+                assert_eq!(path.channel_id(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_stable_hash() {
+        let path = Path::Commitment(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            Sequence::from(1),
+        ));
+
+        // Pinned so that changes to the hash of persisted keys are caught.
+        assert_eq!(path.stable_hash(), 0x9129_18ab_8713_f7b9);
+
+        for path in dummy_paths() {
+            let key = path.to_string();
+
+            assert_eq!(
+                Path::from_str(&key).expect("no error").stable_hash(),
+                path.stable_hash()
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/7", true)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", true)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", true)]
+    #[case("ports/transfer", false)]
+    #[case("clients/07-tendermint-0/clientState", false)]
+    fn test_path_to_channel_end(#[case] path_str: &str, #[case] has_channel_end: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let expected =
+            has_channel_end.then(|| ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero()));
+
+        assert_eq!(path.to_channel_end(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(NEXT_CLIENT_SEQUENCE, true)]
+    #[case(NEXT_CONNECTION_SEQUENCE, true)]
+    #[case(NEXT_CHANNEL_SEQUENCE, true)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceRecv/ports/transfer/channels/channel-0", true)]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", true)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", false)]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/5", false)]
+    #[case("clients/07-tendermint-0/clientState", false)]
+    fn test_path_is_sequence_counter(#[case] path_str: &str, #[case] expected: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.is_sequence_counter(), expected);
+        assert_eq!(path.store() == StoreType::Sequence, expected);
+    }
+
+    #[rstest::rstest]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/5", Some(5))]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/6", Some(6))]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/7", Some(7))]
+    #[case("channelEnds/ports/transfer/channels/channel-0", None)]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", None)]
+    fn test_path_packet_fields(#[case] path_str: &str, #[case] sequence: Option<u64>) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::zero();
+        let expected = sequence.map(|seq| (&port_id, &channel_id, Sequence::from(seq)));
+
+        assert_eq!(path.packet_fields(), expected);
+        assert_eq!(path.is_packet_path(), sequence.is_some());
+    }
+
+    #[rstest::rstest]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/5", Some(5))]
+    #[case("acks/ports/transfer/channels/channel-0/sequences/6", Some(6))]
+    #[case("receipts/ports/transfer/channels/channel-0/sequences/7", Some(7))]
+    #[case("nextSequenceSend/ports/transfer/channels/channel-0", None)]
+    #[case("clients/07-tendermint-0/consensusStates/15-31", None)]
+    fn test_path_sequence(#[case] path_str: &str, #[case] sequence: Option<u64>) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.sequence(), sequence.map(Sequence::from));
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_path_borsh_ser_de_roundtrip() {
+        let paths: Vec<Path> = [
+            "clients/07-tendermint-0/consensusStates/15-31",
+            "ports/transfer",
+            "commitments/ports/transfer/channels/channel-0/sequences/0",
+            "upgradedIBCState/0/upgradedClient",
+        ]
+        .into_iter()
+        .map(|path| Path::from_str(path).expect("no error"))
+        .collect();
+
+        let bytes = borsh::to_vec(&paths).expect("no error");
+
+        assert_eq!(
+            borsh::from_slice::<Vec<Path>>(&bytes).expect("no error"),
+            paths
+        );
+    }
+
+    #[test]
+    fn test_path_validate() {
+        for path in dummy_paths() {
+            path.validate().expect("no error");
+        }
+
+        let path = Path::InterchainAccount(InterchainAccountPath {
+            connection_id: ConnectionId::zero(),
+            owner: "owner/0".to_string(),
+        });
+
+        assert!(matches!(
+            path.validate(),
+            Err(PathError::WrongComponentCount { .. })
+        ));
+
+        // Renders as "clients/7/upgradedClient", i.e. a client path.
+        let path = Path::UpgradeClientState(UpgradeClientStatePath::new("clients".to_string(), 7));
+
+        assert!(matches!(
+            path.validate(),
+            Err(PathError::InvalidClientId { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_path_validate_hand_built() {
+        let client_id: ClientId = serde_json::from_str(r#""07-tendermint-0""#).expect("no error");
+
+        Path::ClientState(ClientStatePath(client_id))
+            .validate()
+            .expect("no error");
+
+        // Deserializing an identifier does not check its format.
+        let client_id: ClientId = serde_json::from_str(r#""bad id""#).expect("no error");
+
+        assert!(matches!(
+            Path::ClientState(ClientStatePath(client_id)).validate(),
+            Err(PathError::InvalidClientId { component, .. }) if component == "bad id"
+        ));
+
+        let port_id: PortId = serde_json::from_str(r#""""#).expect("no error");
+
+        assert!(Path::SeqSend(SeqSendPath(port_id, ChannelId::zero()))
+            .validate()
+            .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_path_try_to_string() {
+        let path = Path::SeqSend(SeqSendPath::new(&PortId::transfer(), &ChannelId::zero()));
+
+        assert_eq!(path.try_to_string().expect("no error"), path.to_string());
+
+        // Would render as "nextSequenceSend/ports/transfer/channels/channel-1/channels/channel-0".
+        let port_id: PortId =
+            serde_json::from_str(r#""transfer/channels/channel-1""#).expect("no error");
+        let path = Path::SeqSend(SeqSendPath(port_id, ChannelId::zero()));
+
+        assert!(matches!(
+            path.try_to_string(),
+            Err(PathError::WrongComponentCount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_path_try_to_string_truncated_failure() {
+        // Renders as "/upgradedIBCState/5/upgradedClient", which parses back
+        // without the leading `/`.
+        let path = Path::UpgradeClientState(UpgradeClientStatePath {
+            upgrade_path: "/upgradedIBCState".to_string(),
+            height: 5,
+        });
+        let key = path.to_string();
+
+        let Err(PathError::TruncatedParseFailure { path: fragment }) = path.try_to_string() else {
+            panic!("expected a truncated parse failure");
+        };
+
+        assert!(fragment.is_truncated());
+        assert_eq!(fragment.as_str(), &key[..PathFragment::CAPACITY]);
+        assert_eq!(
+            PathError::TruncatedParseFailure { path: fragment }.to_string(),
+            format!("`{}...` could not be parsed into a Path", &key[..32])
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_path_borsh_variant_tags() {
+        // Variants are appended, so that the tags of previously encoded paths
+        // do not change.
+        for (path, tag) in [
+            (Path::Connection(ConnectionPath(ConnectionId::zero())), 8),
+            (Path::ClientType(ClientTypePath(ClientId::new_dummy())), 23),
+        ] {
+            assert_eq!(borsh::to_vec(&path).expect("no error")[0], tag);
+        }
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_path_validate_borsh_decoded() {
+        let path = Path::Connection(ConnectionPath(ConnectionId::zero()));
+        let mut bytes = borsh::to_vec(&path).expect("no error");
+
+        // Replaces "connection-0" by a malformed identifier of the same length.
+        let len = bytes.len();
+        bytes[len - 12..].copy_from_slice(b"connection/0");
+
+        let path: Path = borsh::from_slice(&bytes).expect("no error");
+
+        assert!(path.validate().is_err());
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_path_borsh_key_roundtrip() {
+        for path in dummy_paths() {
+            let key = path.borsh_key();
+
+            assert_eq!(
+                borsh::from_slice::<String>(&key).expect("no error"),
+                path.to_string()
+            );
+            assert_eq!(Path::from_borsh_key(&key).expect("no error"), path);
+        }
+
+        let key = Path::Ports(PortPath(PortId::transfer())).borsh_key();
+
+        assert_eq!(key[..4], 14u32.to_le_bytes());
+        assert!(matches!(
+            Path::from_borsh_key(&key[..10]),
+            Err(PathError::InvalidBorshKey { .. })
+        ));
+        assert!(matches!(
+            Path::from_borsh_key(&[key.as_slice(), &[0]].concat()),
+            Err(PathError::InvalidBorshKey { .. })
+        ));
+        assert!(matches!(
+            Path::from_borsh_key(&borsh::to_vec("ports").expect("no error")),
+            Err(PathError::WrongComponentCount { .. })
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/08-wasm-3/clientState",
+        PathKind::ClientState
+    )]
+    #[case(
+        "clients/07-tendermint-0/consensusStates/15-31",
+        "clients/07-tendermint-1/consensusStates/0-1",
+        PathKind::ClientConsensusState
+    )]
+    #[case(
+        "connections/connection-0",
+        "connections/connection-9",
+        PathKind::Connection
+    )]
+    #[case(
+        "channelEnds/ports/transfer/channels/channel-0",
+        "channelEnds/ports/icahost/channels/channel-7",
+        PathKind::ChannelEnd
+    )]
+    #[case(
+        "receipts/ports/transfer/channels/channel-0/sequences/0",
+        "receipts/ports/icahost/channels/channel-2/sequences/12",
+        PathKind::Receipt
+    )]
+    #[case(
+        "upgradedIBCState/0/upgradedConsState",
+        "upgradedIBCState/100/upgradedConsState",
+        PathKind::UpgradeConsensusState
+    )]
+    fn test_path_kind(#[case] path_str: &str, #[case] other_str: &str, #[case] kind: PathKind) {
+        let path = Path::from_str(path_str).expect("no error");
+        let other = Path::from_str(other_str).expect("no error");
+
+        assert_ne!(path, other);
+        assert_eq!(path.kind(), kind);
+        assert_eq!(other.kind(), kind);
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", "ClientState")]
+    #[case(
+        "commitments/ports/transfer/channels/channel-0/sequences/1",
+        "Commitment"
+    )]
+    #[case("nextSequenceAck/ports/transfer/channels/channel-0", "SeqAck")]
+    #[case(NEXT_CLIENT_SEQUENCE, "NextClientSequence")]
+    #[case("capabilities/1", "Capability")]
+    fn test_path_debug_label(#[case] path_str: &str, #[case] label: &str) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.debug_label(), label);
+        assert_eq!(path.describe(), format!("{label}({path_str})"));
+    }
+
+    #[test]
+    fn test_path_prefixes_table() {
+        for path in dummy_paths() {
+            let kind = path.kind();
+            let count = PATH_PREFIXES.iter().filter(|(k, _)| *k == kind).count();
+
+            assert_eq!(
+                count,
+                if kind == PathKind::Params { 3 } else { 1 },
+                "{kind:?}"
+            );
+            assert!(PATH_PREFIXES.contains(&(kind, path.prefix())), "{path}");
+        }
+
+        for params in [
+            ParamsPath::Client,
+            ParamsPath::Connection,
+            ParamsPath::Channel,
+        ] {
+            assert!(PATH_PREFIXES.contains(&(PathKind::Params, params.key())));
+        }
+    }
+
+    #[test]
+    fn test_path_kind_name() {
+        for path in dummy_paths() {
+            assert_eq!(path.kind().name(), format!("{:?}", path.kind()));
+        }
+    }
+
+    #[test]
+    fn test_path_depth() {
+        for path in dummy_paths() {
+            assert_eq!(path.depth(), path.to_string().split('/').count(), "{path}");
+            assert!(path.depth() <= MAX_PATH_COMPONENTS, "{path}");
+        }
+
+        let path = Path::UpgradeClientState(UpgradeClientStatePath::new(
+            "upgrade/upgradedIBCState".to_string(),
+            1,
+        ));
+
+        assert_eq!(path.depth(), 4);
+    }
+
+    #[test]
+    fn test_path_components() {
+        for path in dummy_paths() {
+            assert_eq!(
+                path.components().collect::<Vec<_>>().join("/"),
+                path.to_string()
+            );
+        }
+
+        let path =
+            Path::ClientConsensusState(ClientConsensusStatePath::new(ClientId::new_dummy(), 0, 15));
+        let components = path.components().collect::<Vec<_>>();
+
+        assert_eq!(
+            components,
+            ["clients", "07-tendermint-0", "consensusStates", "0-15"]
+        );
+        assert!(matches!(components[1], Cow::Borrowed(_)));
+        assert!(matches!(components[3], Cow::Owned(_)));
+
+        let path = Path::UpgradeConsensusState(UpgradeConsensusStatePath {
+            upgrade_path: "upgrade/upgradedIBCState".to_string(),
+            height: 7,
+        });
+
+        assert_eq!(
+            path.components().collect::<Vec<_>>(),
+            ["upgrade", "upgradedIBCState", "7", "upgradedConsState"]
+        );
+    }
+
+    #[test]
+    fn test_path_prefix() {
+        for path in dummy_paths() {
+            let path_str = path.to_string();
+
+            assert!(path_str.starts_with(path.prefix()), "{path}");
+            assert_eq!(path_str.split('/').next(), Some(path.prefix()));
+        }
+
+        let path = Path::UpgradeClientState(UpgradeClientStatePath {
+            upgrade_path: "upgrade/upgradedIBCState".to_string(),
+            height: 1,
+        });
+
+        assert_eq!(path.prefix(), "upgrade");
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", CLIENT_PREFIX)]
+    #[case("clients/07-tendermint-0/consensusStates/15-31", CLIENT_PREFIX)]
+    #[case("clients/07-tendermint-0/connections", CLIENT_PREFIX)]
+    #[case("channelEnds/ports/transfer/channels/channel-0", CHANNEL_END_PREFIX)]
+    #[case(
+        "commitments/ports/transfer/channels/channel-0/sequences/0",
+        PACKET_COMMITMENT_PREFIX
+    )]
+    #[case(
+        "nextSequenceSend/ports/transfer/channels/channel-0",
+        NEXT_SEQ_SEND_PREFIX
+    )]
+    fn test_path_matches_prefix(#[case] path_str: &str, #[case] prefix: &str) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert!(path.matches_prefix(prefix));
+        assert!(!path.matches_prefix(CONNECTION_PREFIX));
+        assert!(!path.matches_prefix(PORT_PREFIX));
+        assert!(!path.matches_prefix(""));
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", "clients/07-tendermint-0", true)]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/07-tendermint-0/",
+        true
+    )]
+    #[case("clients/07-tendermint-0/consensusStates/1-2", "clients", true)]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/07-tendermint-0/clientState",
+        true
+    )]
+    #[case("clients/07-tendermint-0/clientState", "", true)]
+    #[case(
+        "clients/07-tendermint-01/clientState",
+        "clients/07-tendermint-0",
+        false
+    )]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/07-tendermint-01",
+        false
+    )]
+    #[case("clients/07-tendermint-0/clientState", "clients/07-tender", false)]
+    #[case("connections/connection-0", "clients", false)]
+    fn test_path_is_under(#[case] path_str: &str, #[case] prefix: &str, #[case] is_under: bool) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.is_under(prefix), is_under);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "clients/07-tendermint-0/consensusStates/15-31",
+        Some("clients/07-tendermint-0/consensusStates")
+    )]
+    #[case(
+        "commitments/ports/transfer/channels/channel-0/sequences/1",
+        Some("commitments/ports/transfer/channels/channel-0/sequences")
+    )]
+    #[case("ports/transfer", Some("ports"))]
+    #[case(NEXT_CLIENT_SEQUENCE, None)]
+    #[case(CLIENT_PARAMS, None)]
+    fn test_path_parent_key(#[case] path_str: &str, #[case] parent: Option<&str>) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.parent_key().as_deref(), parent);
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/clientState", "clients/*/clientState", true)]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/07-tendermint-0/clientState",
+        true
+    )]
+    #[case("clients/07-tendermint-0/clientState", "*/*/*", true)]
+    #[case(
+        "commitments/ports/transfer/channels/channel-0/sequences/1",
+        "commitments/ports/*/channels/*/sequences/*",
+        true
+    )]
+    #[case("clients/07-tendermint-0/clientType", "clients/*/clientState", false)]
+    #[case(
+        "clients/07-tendermint-0/consensusStates/1-2",
+        "clients/*/consensusStates",
+        false
+    )]
+    #[case(
+        "clients/07-tendermint-0/consensusStates",
+        "clients/*/consensusStates/*",
+        false
+    )]
+    #[case("clients/07-tendermint-0/clientState", "clients/*", false)]
+    #[case(
+        "clients/07-tendermint-0/clientState",
+        "clients/07-tendermint-*/clientState",
+        false
+    )]
+    #[case(
+        "acks/ports/transfer/channels/channel-0/sequences/1",
+        "commitments/ports/*/channels/*/sequences/*",
+        false
+    )]
+    fn test_path_matches_glob(
+        #[case] path_str: &str,
+        #[case] pattern: &str,
+        #[case] matches: bool,
+    ) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.matches_glob(pattern), matches);
+    }
+
+    #[test]
+    fn test_path_is_provable() {
+        for path in dummy_paths() {
+            let expected = !matches!(
+                path.kind(),
+                PathKind::ClientConnection
+                    | PathKind::Ports
+                    | PathKind::InterchainAccount
+                    | PathKind::Capability
+                    | PathKind::ClientConsensusStatePrefix
+            );
+
+            assert_eq!(path.is_provable(), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_path_store_predicates() {
+        for path in dummy_paths() {
+            // (client, connection, channel)
+            let expected = match path {
+                Path::NextClientSequence(_)
+                | Path::ClientState(_)
+                | Path::ClientType(_)
+                | Path::ClientConsensusState(_)
+                | Path::ClientUpdateTime(_)
+                | Path::ClientUpdateHeight(_)
+                | Path::ClientConnection(_)
+                | Path::ClientConsensusStatePrefix(_) => (true, false, false),
+                Path::NextConnectionSequence(_) | Path::Connection(_) => (false, true, false),
+                Path::NextChannelSequence(_)
+                | Path::ChannelEnd(_)
+                | Path::SeqSend(_)
+                | Path::SeqRecv(_)
+                | Path::SeqAck(_)
+                | Path::Commitment(_)
+                | Path::Ack(_)
+                | Path::Receipt(_) => (false, false, true),
+                Path::Ports(_)
+                | Path::UpgradeClientState(_)
+                | Path::UpgradeConsensusState(_)
+                | Path::InterchainAccount(_)
+                | Path::Capability(_)
+                | Path::Params(_) => (false, false, false),
+            };
+
+            assert_eq!(
+                (
+                    path.is_client_path(),
+                    path.is_connection_path(),
+                    path.is_channel_path()
+                ),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_store() {
+        for path in dummy_paths() {
+            let expected = match path {
+                Path::ClientState(_)
+                | Path::ClientType(_)
+                | Path::ClientConsensusState(_)
+                | Path::ClientUpdateTime(_)
+                | Path::ClientUpdateHeight(_)
+                | Path::ClientConnection(_)
+                | Path::ClientConsensusStatePrefix(_)
+                | Path::Params(ParamsPath::Client) => StoreType::Client,
+                Path::Connection(_)
+                | Path::InterchainAccount(_)
+                | Path::Params(ParamsPath::Connection) => StoreType::Connection,
+                Path::Ports(_)
+                | Path::ChannelEnd(_)
+                | Path::Commitment(_)
+                | Path::Ack(_)
+                | Path::Receipt(_)
+                | Path::Capability(_)
+                | Path::Params(ParamsPath::Channel) => StoreType::Channel,
+                Path::UpgradeClientState(_) | Path::UpgradeConsensusState(_) => StoreType::Upgrade,
+                Path::NextClientSequence(_)
+                | Path::NextConnectionSequence(_)
+                | Path::NextChannelSequence(_)
+                | Path::SeqSend(_)
+                | Path::SeqRecv(_)
+                | Path::SeqAck(_) => StoreType::Sequence,
+            };
+
+            assert_eq!(path.store(), expected, "{path}");
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("/nextClientSequence")]
+    #[case("/clients/07-tendermint-0/clientState")]
+    #[case("/clients/07-tendermint-0/consensusStates/15-31")]
+    #[case("/channelEnds/ports/transfer/channels/channel-0")]
+    #[case("/commitments/ports/transfer/channels/channel-0/sequences/0")]
+    #[case("/upgradedIBCState/0/upgradedClient")]
+    fn test_leading_slash_parsing(#[case] path_str: &str) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path, Path::from_str(&path_str[1..]).expect("no error"));
+        assert_eq!(path.to_string(), path_str[1..]);
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_path_json_schema() {
+        let schema = schemars::schema_for!(Path);
+
+        let variants = schema
+            .schema
+            .subschemas
+            .and_then(|subschemas| subschemas.one_of)
+            .expect("no error");
+
+        assert_eq!(variants.len(), dummy_paths().len());
+        assert!(schema.definitions.contains_key("ClientStatePath"));
+        assert!(schema.definitions.contains_key("CommitmentPath"));
+    }
+
+    #[rstest::rstest]
+    #[case("", 0)]
+    #[case("clients//clientState", 1)]
+    #[case("/", 0)]
+    #[case("//clients/07-tendermint-0/clientState", 0)]
+    #[case("/clients//clientState", 1)]
+    #[case("connections/connection-0/", 2)]
+    #[case("connections//", 1)]
+    #[case("commitments/ports/transfer/channels/channel-0/sequences/", 6)]
+    fn test_empty_component_parsing(#[case] path_str: &str, #[case] index: usize) {
+        assert!(matches!(
+            Path::from_str(path_str),
+            Err(PathError::EmptyComponent { index: i }) if i == index
+        ));
+    }
+
+    #[test]
+    fn test_too_many_components_parsing() {
+        assert!(matches!(
+            Path::from_str("commitments/ports/transfer/channels/channel-0/sequences/0/0"),
+            Err(PathError::TooManyComponents { max: 7 })
+        ));
+        assert!(matches!(
+            Path::from_str(&"a/".repeat(10_000)),
+            Err(PathError::TooManyComponents { max: 7 })
+        ));
+        assert!(matches!(
+            Path::from_str(&"/".repeat(10_000)),
+            Err(PathError::EmptyComponent { index: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_path_fragment_truncation() {
+        let fragment = PathFragment::new("channel-0");
+
+        assert_eq!(fragment, "channel-0");
+        assert!(!fragment.is_truncated());
+
+        let component = "a".repeat(40);
+        let fragment = PathFragment::new(&component);
+
+        assert_eq!(fragment.as_str(), &component[..PathFragment::CAPACITY]);
+        assert!(fragment.is_truncated());
+        assert_ne!(fragment, component.as_str());
+        assert_eq!(fragment.to_string(), format!("{}...", &component[..32]));
+
+        // The cut falls within the last `é`, which is dropped entirely.
+        let component = format!("a{}", "é".repeat(16));
+        let fragment = PathFragment::new(&component);
+
+        assert_eq!(fragment.as_str(), &component[..31]);
+        assert!(fragment.is_truncated());
+
+        let client_id = "x".repeat(100);
+
+        assert!(matches!(
+            Path::from_str(&format!("clients/{client_id}/clientState")),
+            Err(PathError::InvalidClientId { component, .. })
+                if component.is_truncated() && component.as_str() == &client_id[..32]
+        ));
+    }
+
+    #[test]
+    fn test_sub_path_try_from_path() {
+        let commitment =
+            CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1));
+
+        assert_eq!(
+            CommitmentPath::try_from(Path::Commitment(commitment.clone())).expect("no error"),
+            commitment
+        );
+        assert!(matches!(
+            AckPath::try_from(Path::Commitment(commitment)),
+            Err(PathError::WrongVariant {
+                expected: PathKind::Ack,
+                found: PathKind::Commitment
+            })
+        ));
+        assert_eq!(
+            PathError::WrongVariant {
+                expected: PathKind::Ack,
+                found: PathKind::Commitment
+            }
+            .to_string(),
+            "wrong path variant: expected Ack, found Commitment"
+        );
+    }
+
+    #[test]
+    fn test_parse_failure_kinds() {
+        assert!(matches!(
+            Path::from_str("channels/channel-0"),
+            Err(PathError::UnknownPrefix { prefix }) if prefix == "channels"
+        ));
+        assert!(matches!(
+            Path::from_str("clients"),
+            Err(PathError::WrongComponentCount {
+                expected: 3,
+                found: 1
+            })
+        ));
+        assert!(matches!(
+            Path::from_str("nextSequenceSend/ports/transfer"),
+            Err(PathError::WrongComponentCount {
+                expected: 5,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            Path::from_str("clients/07-tendermint-0/clientStates"),
+            Err(PathError::UnexpectedComponent { component }) if component == "clientStates"
+        ));
+        assert!(matches!(
+            Path::from_str("channelEnds/port/transfer/channels/channel-0"),
+            Err(PathError::MissingPortSegment { component }) if component == "port"
+        ));
+        assert!(matches!(
+            Path::from_str("connections/conn-0"),
+            Err(PathError::InvalidConnectionId { component, .. }) if component == "conn-0"
+        ));
+        assert!(matches!(
+            Path::from_str("acks/ports/transfer/channels/channel-0/sequences/x"),
+            Err(PathError::InvalidSequence { component, .. }) if component == "x"
+        ));
+        assert!(matches!(
+            Path::from_str("clients/07-tendermint-0/consensusStates/15"),
+            Err(PathError::InvalidHeight { height }) if height == "15"
+        ));
+        assert!(matches!(
+            Path::from_str("upgradedIBCState/x/upgradedClient"),
+            Err(PathError::InvalidHeight { height }) if height == "x"
+        ));
+        assert!(matches!(
+            Path::from_str("ports/p"),
+            Err(PathError::InvalidPortId { component, .. }) if component == "p"
+        ));
+        assert!(matches!(
+            Path::from_str("channelEnds/ports/transfer/channels/chan-0"),
+            Err(PathError::InvalidChannelId { component, .. }) if component == "chan-0"
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_failure_source() {
+        use std::error::Error;
+
+        let err = Path::from_str("clients/x/clientState").expect_err("invalid client id");
+
+        assert!(matches!(err, PathError::InvalidClientId { .. }));
+
+        let source = err
+            .source()
+            .and_then(|source| source.downcast_ref::<IdentifierError>())
+            .expect("identifier error source");
+
+        assert_eq!(
+            source.to_string(),
+            ClientId::from_str("x").expect_err("invalid").to_string()
+        );
+
+        let err = Path::from_str("receipts/ports/transfer/channels/channel-0/sequences/x")
+            .expect_err("invalid sequence");
+
+        assert!(matches!(err, PathError::InvalidSequence { .. }));
+        assert!(err
+            .source()
+            .and_then(|source| source.downcast_ref::<IdentifierError>())
+            .is_some());
+        assert!(PathError::TooManyComponents { max: 7 }.source().is_none());
     }
 
-    let first = *components.first()?;
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/consensusStates/99999999999999999999-1")]
+    #[case("clients/07-tendermint-0/consensusStates/1-18446744073709551616/processedTime")]
+    #[case("upgradedIBCState/18446744073709551616/upgradedConsState")]
+    fn test_height_overflow_parsing(#[case] path: &str) {
+        assert!(matches!(
+            Path::from_str(path),
+            Err(PathError::HeightOverflow { .. })
+        ));
+    }
 
-    if first != PACKET_RECEIPT_PREFIX {
-        return None;
+    #[rstest::rstest]
+    #[case("clients/07-tendermint-0/consensusStates/x-1")]
+    #[case("clients/07-tendermint-0/consensusStates/1--1/processedTime")]
+    #[case("upgradedIBCState/1x/upgradedConsState")]
+    fn test_height_non_numeric_parsing(#[case] path: &str) {
+        assert!(matches!(
+            Path::from_str(path),
+            Err(PathError::InvalidHeight { .. })
+        ));
     }
 
-    let port = parse_ports(&components[1..=2]);
-    let channel = parse_channels(&components[3..=4]);
-    let sequence = parse_sequences(&components[5..]);
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sub_path_serde_field_names() {
+        let path = ClientConsensusStatePath::new(ClientId::new_dummy(), 1, 2);
+        let json = r#"{"clientId":"07-tendermint-0","revisionNumber":1,"revisionHeight":2}"#;
 
-    let Some(Path::Ports(PortPath(port_id))) = port else {
-        return None;
-    };
+        assert_eq!(serde_json::to_string(&path).expect("no error"), json);
+        assert_eq!(
+            serde_json::from_str::<ClientConsensusStatePath>(json).expect("no error"),
+            path
+        );
 
-    let Some(SubPath::Channels(channel_id)) = channel else {
-        return None;
-    };
+        let path = CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), 3.into());
 
-    let Some(SubPath::Sequences(sequence)) = sequence else {
-        return None;
-    };
+        assert_eq!(
+            serde_json::to_string(&path).expect("no error"),
+            r#"{"portId":"transfer","channelId":"channel-0","sequence":3}"#
+        );
 
-    Some(
-        ReceiptPath {
-            port_id,
-            channel_id,
-            sequence,
-        }
-        .into(),
-    )
-}
+        let path = UpgradeClientStatePath::new_with_default_path(5);
 
-fn parse_upgrade_client_state(components: &[&str]) -> Option<Path> {
-    if components.len() != 3 {
-        return None;
+        assert_eq!(
+            serde_json::to_string(&path).expect("no error"),
+            r#"{"upgradePath":"upgradedIBCState","height":5}"#
+        );
     }
 
-    let last = *components.last()?;
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_path_as_string_serde_roundtrip() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Entry {
+            #[serde(with = "path_as_string")]
+            path: Path,
+        }
 
-    if last != UPGRADED_CLIENT_STATE {
-        return None;
+        let entry = Entry {
+            path: Path::ClientState(ClientStatePath(ClientId::new_dummy())),
+        };
+        let json = r#"{"path":"clients/07-tendermint-0/clientState"}"#;
+
+        assert_eq!(serde_json::to_string(&entry).expect("no error"), json);
+        assert_eq!(
+            serde_json::from_str::<Entry>(json).expect("no error"),
+            entry
+        );
+        assert!(serde_json::from_str::<Entry>(r#"{"path":"clients/clientState"}"#).is_err());
     }
 
-    let upgrade_path = components.first()?.to_string();
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_path_display_from_str_roundtrip(path in strategies::path()) {
+            proptest::prop_assert_eq!(Path::from_str(&path.to_string()).ok(), Some(path));
+        }
 
-    let height = u64::from_str(components[1]).ok()?;
+        #[test]
+        fn test_path_display_has_no_empty_components(path in strategies::path()) {
+            let path = path.to_string();
+            proptest::prop_assert!(path.split('/').all(|component| !component.is_empty()), "{path}");
+        }
 
-    Some(
-        UpgradeClientStatePath {
-            upgrade_path,
-            height,
+        #[test]
+        fn test_path_encoded_len_matches_display(path in strategies::path()) {
+            proptest::prop_assert_eq!(path.encoded_len(), path.to_string().len());
         }
-        .into(),
-    )
-}
 
-fn parse_upgrade_consensus_state(components: &[&str]) -> Option<Path> {
-    if components.len() != 3 {
-        return None;
-    }
+        #[test]
+        fn test_path_components_join_to_display(path in strategies::path()) {
+            proptest::prop_assert_eq!(path.components().collect::<Vec<_>>().join("/"), path.to_string());
+        }
 
-    let last = *components.last()?;
+        #[test]
+        fn test_path_eq_matches_key_bytes_eq(a in strategies::path(), b in strategies::path()) {
+            let parsed = Path::from_str(&a.to_string()).expect("no error");
 
-    if last != UPGRADED_CLIENT_CONSENSUS_STATE {
-        return None;
+            proptest::prop_assert_eq!(a == b, a.clone().into_bytes() == b.clone().into_bytes());
+            proptest::prop_assert_eq!(a == parsed, a.clone().into_bytes() == parsed.into_bytes());
+        }
     }
 
-    let upgrade_path = components.first()?.to_string();
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn test_path_strategy_shrinks_to_valid_paths() {
+        use proptest::strategy::{Strategy, ValueTree};
+        use proptest::test_runner::TestRunner;
 
-    let height = u64::from_str(components[1]).ok()?;
+        let mut runner = TestRunner::deterministic();
 
-    Some(
-        UpgradeConsensusStatePath {
-            upgrade_path,
-            height,
-        }
-        .into(),
-    )
-}
+        for _ in 0..64 {
+            let mut tree = strategies::path().new_tree(&mut runner).expect("no error");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const DEFAULT_CLIENT_ID_STR: &str = "07-tendermint-0";
-    impl ClientId {
-        pub fn new_dummy() -> Self {
-            ClientId::from_str(DEFAULT_CLIENT_ID_STR)
-                .expect("should not fail since we use a valid client id")
+            loop {
+                let path = tree.current();
+                path.validate().expect("no error");
+
+                if !tree.simplify() {
+                    break;
+                }
+            }
         }
     }
-    #[rstest::rstest]
-    #[case(NEXT_CLIENT_SEQUENCE, Path::NextClientSequence(NextClientSequencePath))]
-    #[case(
-        NEXT_CONNECTION_SEQUENCE,
-        Path::NextConnectionSequence(NextConnectionSequencePath)
-    )]
-    #[case(
-        NEXT_CHANNEL_SEQUENCE,
-        Path::NextChannelSequence(NextChannelSequencePath)
-    )]
-    #[case(
-        "clients/07-tendermint-0/clientState",
-        Path::ClientState(ClientStatePath(ClientId::new_dummy()))
-    )]
-    #[case(
-        "clients/07-tendermint-0/consensusStates/15-31",
-        Path::ClientConsensusState(ClientConsensusStatePath {
-            client_id: ClientId::new_dummy(),
-            revision_number: 15,
-            revision_height: 31,
-        })
-    )]
-    #[case(
-        "clients/07-tendermint-0/consensusStates/15-31/processedTime",
-        Path::ClientUpdateTime(ClientUpdateTimePath {
-            client_id: ClientId::new_dummy(),
-            revision_number: 15,
-            revision_height: 31,
-        })
-    )]
-    #[case(
-        "clients/07-tendermint-0/consensusStates/15-31/processedHeight",
-        Path::ClientUpdateHeight(ClientUpdateHeightPath {
-            client_id: ClientId::new_dummy(),
-            revision_number: 15,
-            revision_height: 31,
-        })
-    )]
-    #[case(
-        "clients/07-tendermint-0/connections",
-        Path::ClientConnection(ClientConnectionPath(ClientId::new_dummy()))
-    )]
-    #[case(
-        "connections/connection-0",
-        Path::Connection(ConnectionPath(ConnectionId::zero()))
-    )]
-    #[case("ports/transfer", Path::Ports(PortPath(PortId::transfer())))]
-    #[case(
-        "channelEnds/ports/transfer/channels/channel-0",
-        Path::ChannelEnd(ChannelEndPath(PortId::transfer(), ChannelId::zero()))
-    )]
-    #[case(
-        "nextSequenceSend/ports/transfer/channels/channel-0",
-        Path::SeqSend(SeqSendPath(PortId::transfer(), ChannelId::zero()))
-    )]
-    #[case(
-        "nextSequenceRecv/ports/transfer/channels/channel-0",
-        Path::SeqRecv(SeqRecvPath(PortId::transfer(), ChannelId::zero()))
-    )]
-    #[case(
-        "nextSequenceAck/ports/transfer/channels/channel-0",
-        Path::SeqAck(SeqAckPath(PortId::transfer(), ChannelId::zero()))
-    )]
-    #[case(
-        "commitments/ports/transfer/channels/channel-0/sequences/0",
-        Path::Commitment(CommitmentPath {
-            port_id: PortId::transfer(),
-            channel_id: ChannelId::zero(),
-            sequence: Sequence::from(0),
-        })
-    )]
-    #[case(
-        "acks/ports/transfer/channels/channel-0/sequences/0",
-        Path::Ack(AckPath {
-            port_id: PortId::transfer(),
-            channel_id: ChannelId::zero(),
-            sequence: Sequence::from(0),
-        })
-    )]
-    #[case(
-        "receipts/ports/transfer/channels/channel-0/sequences/0",
-        Path::Receipt(ReceiptPath {
-            port_id: PortId::transfer(),
-            channel_id: ChannelId::zero(),
-            sequence: Sequence::from(0),
-        })
-    )]
-    #[case(
-        "upgradedIBCState/0/upgradedClient",
-        Path::UpgradeClientState(UpgradeClientStatePath {
-            upgrade_path: UPGRADED_IBC_STATE.to_string(),
-            height: 0,
-        })
-    )]
-    #[case(
-        "upgradedIBCState/0/upgradedConsState",
-        Path::UpgradeConsensusState(UpgradeConsensusStatePath {
-            upgrade_path: UPGRADED_IBC_STATE.to_string(),
-            height: 0,
-        })
-    )]
-    fn test_successful_parsing(#[case] path_str: &str, #[case] path: Path) {
-        // can be parsed into Path
-        assert_eq!(Path::from_str(path_str).expect("no error"), path);
-        // can be converted back to string
-        assert_eq!(path_str, path.to_string());
+
+    #[test]
+    fn test_sample_paths_eq_matches_key_bytes_eq() {
+        for a in dummy_paths() {
+            for b in dummy_paths() {
+                assert_eq!(
+                    a == b,
+                    a.clone().into_bytes() == b.clone().into_bytes(),
+                    "{a} {b}"
+                );
+            }
+        }
     }
 
     #[rstest::rstest]
@@ -1299,21 +6081,110 @@ mod tests {
         assert!(Path::from_str(path_str).is_err());
     }
 
+    #[rstest::rstest]
+    #[case("99-custom-0")]
+    #[case("08-wasm-12")]
+    #[case("custom_client+type-7")]
+    fn test_parse_non_standard_client_types(#[case] client_id: &str) {
+        let path_str = format!("clients/{client_id}/clientState");
+        let expected = Path::ClientState(ClientStatePath(
+            ClientId::from_str(client_id).expect("no error"),
+        ));
+
+        assert_eq!(Path::from_str(&path_str).expect("no error"), expected);
+        assert_eq!(
+            Path::from_str_lenient(&path_str).expect("no error"),
+            expected
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("clients/07-tm-0/clientState")]
+    #[case("clients/client@0/consensusStates/1-2")]
+    #[case("connections/conn-0")]
+    #[case("channelEnds/ports/transfer/channels/chan_0")]
+    #[case("commitments/ports/my:port/channels/channel-0/sequences/1")]
+    fn test_parse_with_relaxed_ids(#[case] path_str: &str) {
+        assert!(Path::from_str(path_str).is_err());
+
+        let path = Path::from_str_with(path_str, RelaxedIdValidator).expect("no error");
+
+        assert_eq!(path.to_string(), path_str);
+        assert!(path.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_with_relaxed_ids_checks_structure() {
+        assert!(matches!(
+            Path::from_str_with("clients/07-tm-0/clientStates", RelaxedIdValidator),
+            Err(PathError::UnexpectedComponent { component }) if component == "clientStates"
+        ));
+        assert!(matches!(
+            Path::from_str_with("clients//clientState", RelaxedIdValidator),
+            Err(PathError::EmptyComponent { index: 1 })
+        ));
+        assert!(matches!(
+            Path::from_str_with(
+                "acks/ports/transfer/channels/chan_0/sequences/x",
+                RelaxedIdValidator
+            ),
+            Err(PathError::InvalidSequence { component, .. }) if component == "x"
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_custom_validator() {
+        struct ShortClientIds;
+
+        impl IdValidator for ShortClientIds {
+            fn validate_client_id(&self, id: &str) -> Result<(), IdentifierError> {
+                crate::validate::validate_identifier_chars(id)
+            }
+        }
+
+        assert_eq!(
+            Path::from_str_with("clients/07-tm-0/clientState", ShortClientIds)
+                .expect("no error")
+                .client_id()
+                .map(ClientId::as_str),
+            Some("07-tm-0")
+        );
+        assert!(matches!(
+            Path::from_str_with("connections/conn-0", ShortClientIds),
+            Err(PathError::InvalidConnectionId { component, .. }) if component == "conn-0"
+        ));
+
+        for (path_str, path) in sample_paths() {
+            assert_eq!(
+                Path::from_str_with(path_str, StrictIdValidator).expect("no error"),
+                path
+            );
+        }
+    }
+
     #[test]
     fn test_parse_client_paths_fn() {
         let path = "clients/07-tendermint-0/clientState";
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_client_paths(&components),
+            parse_client_paths(&components, &StrictIdValidator).ok(),
             Some(Path::ClientState(ClientStatePath(ClientId::new_dummy())))
         );
 
+        let path = "clients/07-tendermint-0/clientType";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_client_paths(&components, &StrictIdValidator).ok(),
+            Some(Path::ClientType(ClientTypePath(ClientId::new_dummy())))
+        );
+
         let path = "clients/07-tendermint-0/consensusStates/15-31";
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_client_paths(&components),
+            parse_client_paths(&components, &StrictIdValidator).ok(),
             Some(Path::ClientConsensusState(ClientConsensusStatePath {
                 client_id: ClientId::new_dummy(),
                 revision_number: 15,
@@ -1328,7 +6199,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_client_paths(&components),
+            parse_client_paths(&components, &StrictIdValidator).ok(),
             Some(Path::ClientUpdateTime(ClientUpdateTimePath {
                 client_id: ClientId::new_dummy(),
                 revision_number: 15,
@@ -1340,7 +6211,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_client_paths(&components),
+            parse_client_paths(&components, &StrictIdValidator).ok(),
             Some(Path::ClientUpdateHeight(ClientUpdateHeightPath {
                 client_id: ClientId::new_dummy(),
                 revision_number: 15,
@@ -1355,7 +6226,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_connections(&components),
+            parse_connections(&components, &StrictIdValidator).ok(),
             Some(Path::Connection(ConnectionPath(ConnectionId::zero()))),
         );
     }
@@ -1366,18 +6237,73 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_ports(&components),
+            parse_ports(&components, &StrictIdValidator).ok(),
             Some(Path::Ports(PortPath(PortId::transfer()))),
         );
     }
 
+    #[rstest::rstest]
+    #[case("transfer")]
+    #[case("icahost")]
+    #[case("icacontroller-cosmos1owner")]
+    #[case("wasm.xyz")]
+    #[case("wasm.osmo1-contract.v2")]
+    #[case("my_module.sub-module.port")]
+    fn test_port_id_round_trips(#[case] port_id: &str) {
+        let port_id = PortId::from_str(port_id).expect("no error");
+        let channel_id = ChannelId::new(3);
+
+        let paths = [
+            Path::Ports(PortPath::new(&port_id)),
+            Path::ChannelEnd(ChannelEndPath::new(&port_id, &channel_id)),
+            Path::Commitment(CommitmentPath::new(
+                &port_id,
+                &channel_id,
+                Sequence::from(9),
+            )),
+        ];
+
+        for path in paths {
+            let path_str = path.to_string();
+
+            assert!(path_str.contains(&format!("ports/{port_id}")), "{path_str}");
+            assert_eq!(Path::from_str(&path_str).expect("no error"), path);
+            assert_eq!(path.port_id(), Some(&port_id));
+        }
+    }
+
+    #[test]
+    fn test_parse_port_segment_fn() {
+        assert_eq!(
+            parse_port_segment(&["ports", "transfer"], &StrictIdValidator).ok(),
+            Some(PortId::transfer())
+        );
+        assert!(matches!(
+            parse_port_segment(&["Ports", "transfer"], &StrictIdValidator),
+            Err(PathError::MissingPortSegment { component }) if component == "Ports"
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case("channelEnds/portz/transfer/channels/channel-0")]
+    #[case("nextSequenceSend/portz/transfer/channels/channel-0")]
+    #[case("commitments/portz/transfer/channels/channel-0/sequences/0")]
+    #[case("acks/portz/transfer/channels/channel-0/sequences/0")]
+    #[case("receipts/portz/transfer/channels/channel-0/sequences/0")]
+    fn test_corrupted_port_segment_parsing(#[case] path_str: &str) {
+        assert!(matches!(
+            Path::from_str(path_str),
+            Err(PathError::MissingPortSegment { component }) if component == "portz"
+        ));
+    }
+
     #[test]
     fn test_parse_channels_fn() {
         let path = "channels/channel-0";
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_channels(&components),
+            parse_channels(&components).ok(),
             Some(SubPath::Channels(ChannelId::zero())),
         );
     }
@@ -1388,7 +6314,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_sequences(&components),
+            parse_sequences(&components).ok(),
             Some(SubPath::Sequences(Sequence::from(0)))
         );
     }
@@ -1399,7 +6325,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_channel_ends(&components),
+            parse_channel_ends(&components, &StrictIdValidator).ok(),
             Some(Path::ChannelEnd(ChannelEndPath(
                 PortId::transfer(),
                 ChannelId::zero()
@@ -1413,7 +6339,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_seqs(&components),
+            parse_seqs(&components, &StrictIdValidator).ok(),
             Some(Path::SeqSend(SeqSendPath(
                 PortId::transfer(),
                 ChannelId::zero()
@@ -1424,7 +6350,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_seqs(&components),
+            parse_seqs(&components, &StrictIdValidator).ok(),
             Some(Path::SeqRecv(SeqRecvPath(
                 PortId::transfer(),
                 ChannelId::zero()
@@ -1435,12 +6361,29 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_seqs(&components),
+            parse_seqs(&components, &StrictIdValidator).ok(),
             Some(Path::SeqAck(SeqAckPath(
                 PortId::transfer(),
                 ChannelId::zero()
             ))),
         );
+
+        let path = "garbage/ports/transfer/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(matches!(
+            parse_seqs(&components, &StrictIdValidator),
+            Err(PathError::UnknownPrefix { prefix }) if prefix == "garbage"
+        ));
+
+        // The prefix is checked before the port and channel identifiers.
+        let path = "garbage/ports/!/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(matches!(
+            parse_seqs(&components, &StrictIdValidator),
+            Err(PathError::UnknownPrefix { prefix }) if prefix == "garbage"
+        ));
     }
 
     #[test]
@@ -1449,7 +6392,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_commitments(&components),
+            parse_commitments(&components, &StrictIdValidator).ok(),
             Some(Path::Commitment(CommitmentPath {
                 port_id: PortId::transfer(),
                 channel_id: ChannelId::zero(),
@@ -1458,13 +6401,43 @@ mod tests {
         );
     }
 
+    #[rstest::rstest]
+    #[case("0", Some(0))]
+    #[case("18446744073709551615", Some(u64::MAX))]
+    #[case("18446744073709551616", None)]
+    #[case("-1", None)]
+    #[case("+1", None)]
+    #[case("01", None)]
+    #[case("00", None)]
+    #[case("one", None)]
+    fn test_parse_commitments_sequence_bounds(
+        #[case] sequence: &str,
+        #[case] expected: Option<u64>,
+    ) {
+        let path = format!("commitments/ports/transfer/channels/channel-0/sequences/{sequence}");
+        let components: Vec<&str> = path.split('/').collect();
+
+        match expected {
+            Some(expected) => {
+                let path = parse_commitments(&components, &StrictIdValidator).expect("no error");
+
+                assert_eq!(path.sequence(), Some(Sequence::from(expected)));
+                assert_eq!(path.to_string(), components.join("/"));
+            }
+            None => assert!(matches!(
+                parse_commitments(&components, &StrictIdValidator),
+                Err(PathError::InvalidSequence { component, .. }) if component == sequence
+            )),
+        }
+    }
+
     #[test]
     fn test_parse_acks_fn() {
         let path = "acks/ports/transfer/channels/channel-0/sequences/0";
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_acks(&components),
+            parse_acks(&components, &StrictIdValidator).ok(),
             Some(Path::Ack(AckPath {
                 port_id: PortId::transfer(),
                 channel_id: ChannelId::zero(),
@@ -1479,7 +6452,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_receipts(&components),
+            parse_receipts(&components, &StrictIdValidator).ok(),
             Some(Path::Receipt(ReceiptPath {
                 port_id: PortId::transfer(),
                 channel_id: ChannelId::zero(),
@@ -1494,7 +6467,7 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_upgrade_client_state(&components),
+            parse_upgrade_client_state(&components).ok(),
             Some(Path::UpgradeClientState(UpgradeClientStatePath {
                 upgrade_path: UPGRADED_IBC_STATE.to_string(),
                 height: 0,
@@ -1508,11 +6481,232 @@ mod tests {
         let components: Vec<&str> = path.split('/').collect();
 
         assert_eq!(
-            parse_upgrade_consensus_state(&components),
+            parse_upgrade_consensus_state(&components).ok(),
             Some(Path::UpgradeConsensusState(UpgradeConsensusStatePath {
                 upgrade_path: UPGRADED_IBC_STATE.to_string(),
                 height: 0,
             })),
         )
     }
+
+    #[rstest::rstest]
+    #[case("upgradedIBCState/7/upgradedClient", UPGRADED_IBC_STATE)]
+    #[case("upgrade/7/upgradedClient", "upgrade")]
+    #[case(
+        "upgrade/upgradedIBCState/7/upgradedConsState",
+        "upgrade/upgradedIBCState"
+    )]
+    fn test_parse_custom_upgrade_path(#[case] path_str: &str, #[case] upgrade_path: &str) {
+        let expected: Path = if path_str.ends_with(UPGRADED_CLIENT_STATE) {
+            UpgradeClientStatePath::new(upgrade_path.to_string(), 7).into()
+        } else {
+            UpgradeConsensusStatePath::new(upgrade_path.to_string(), 7).into()
+        };
+
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path, expected);
+        assert_eq!(path.to_string(), path_str);
+    }
+
+    #[rstest::rstest]
+    #[case("upgradedIBCState/7/upgradedClient")]
+    #[case("upgrade/upgradedIBCState/7/upgradedClient")]
+    fn test_upgrade_client_state_path_from_str(#[case] path_str: &str) {
+        let path = UpgradeClientStatePath::from_str(path_str).expect("no error");
+
+        assert_eq!(path.height, 7);
+        assert_eq!(path.to_string(), path_str);
+        assert_eq!(
+            Path::from_str(path_str).expect("no error"),
+            Path::UpgradeClientState(path)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("upgradedIBCState/7/upgradedConsState")]
+    #[case("upgrade/upgradedIBCState/7/upgradedConsState")]
+    fn test_upgrade_consensus_state_path_from_str(#[case] path_str: &str) {
+        let path = UpgradeConsensusStatePath::from_str(path_str).expect("no error");
+
+        assert_eq!(path.height, 7);
+        assert_eq!(path.to_string(), path_str);
+        assert_eq!(
+            Path::from_str(path_str).expect("no error"),
+            Path::UpgradeConsensusState(path)
+        );
+    }
+
+    #[test]
+    fn test_upgrade_paths_from_non_upgrade_str() {
+        assert!(matches!(
+            UpgradeClientStatePath::from_str("upgradedIBCState/7/upgradedConsState"),
+            Err(PathError::UnexpectedComponent { component }) if component == "upgradedConsState"
+        ));
+        assert!(matches!(
+            UpgradeConsensusStatePath::from_str("clients/07-tendermint-0/clientState"),
+            Err(PathError::UnexpectedComponent { component }) if component == "clientState"
+        ));
+        assert!(matches!(
+            UpgradeConsensusStatePath::from_str("ports/transfer"),
+            Err(PathError::WrongComponentCount { .. })
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case("upgradedIBCState/18446744073709551615/upgradedClient")]
+    #[case("upgradedIBCState/18446744073709551615/upgradedConsState")]
+    #[case("upgrade/upgradedIBCState/18446744073709551615/upgradedClient")]
+    fn test_upgrade_path_max_height_roundtrip(#[case] path_str: &str) {
+        let path = Path::from_str(path_str).expect("no error");
+
+        assert_eq!(path.plan_height(), Some(u64::MAX));
+        assert_eq!(path.to_string(), path_str);
+    }
+
+    #[rstest::rstest]
+    #[case("upgradedIBCState/-1/upgradedClient")]
+    #[case("upgradedIBCState/+1/upgradedClient")]
+    #[case("upgradedIBCState/-1/upgradedConsState")]
+    #[case("upgradedIBCState/1.5/upgradedConsState")]
+    #[case("upgradedIBCState/007/upgradedClient")]
+    #[case("upgradedIBCState/00/upgradedConsState")]
+    #[case("clients/07-tendermint-0/consensusStates/01-5")]
+    #[case("clients/07-tendermint-0/consensusStates/1-+5")]
+    #[case("clients/07-tendermint-0/consensusStates/+1-5/processedTime")]
+    #[case("clients/07-tendermint-0/consensusStates/1-05/processedHeight")]
+    fn test_path_invalid_height(#[case] path_str: &str) {
+        assert!(matches!(
+            Path::from_str(path_str),
+            Err(PathError::InvalidHeight { .. })
+        ));
+    }
+
+    #[test]
+    fn test_upgrade_path_height_overflow() {
+        assert!(matches!(
+            Path::from_str("upgradedIBCState/18446744073709551616/upgradedClient"),
+            Err(PathError::HeightOverflow { .. })
+        ));
+    }
+
+    #[test]
+    fn test_upgrade_path_constructors() {
+        let client_state = Path::upgraded_client_state(7);
+        let consensus_state = Path::upgraded_client_consensus_state(7);
+
+        assert_eq!(
+            client_state.to_string(),
+            "upgradedIBCState/7/upgradedClient"
+        );
+        assert_eq!(
+            consensus_state.to_string(),
+            "upgradedIBCState/7/upgradedConsState"
+        );
+        assert_eq!(client_state.plan_height(), Some(7));
+        assert_eq!(consensus_state.plan_height(), Some(7));
+
+        assert_eq!(
+            Path::Ports(PortPath(PortId::transfer())).plan_height(),
+            None
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("upgradedClient")]
+    #[case("7/upgradedConsState")]
+    fn test_parse_upgrade_path_without_root(#[case] path_str: &str) {
+        assert!(matches!(
+            Path::from_str(path_str),
+            Err(PathError::WrongComponentCount { expected: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_interchain_accounts_fn() {
+        let path = "interchainAccounts/connection-0/owner";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_interchain_accounts(&components, &StrictIdValidator).ok(),
+            Some(Path::InterchainAccount(InterchainAccountPath {
+                connection_id: ConnectionId::zero(),
+                owner: "owner".to_string(),
+            })),
+        );
+
+        let path = "interchainAccounts/connection-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(parse_interchain_accounts(&components, &StrictIdValidator).is_err());
+
+        let path = "interchainAccounts/connection-0/owner/extra";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(parse_interchain_accounts(&components, &StrictIdValidator).is_err());
+    }
+
+    #[test]
+    fn test_parse_capabilities_fn() {
+        let path = "capabilities/1";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_capabilities(&components).ok(),
+            Some(Path::Capability(CapabilityPath(1))),
+        );
+
+        let path = "capabilities/one";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(matches!(
+            parse_capabilities(&components),
+            Err(PathError::InvalidCapabilityIndex { index }) if index == "one"
+        ));
+
+        let path = "capabilities/-1";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert!(matches!(
+            parse_capabilities(&components),
+            Err(PathError::InvalidCapabilityIndex { index }) if index == "-1"
+        ));
+
+        for index in ["+1", "01"] {
+            assert!(matches!(
+                Path::from_str(&format!("capabilities/{index}")),
+                Err(PathError::InvalidCapabilityIndex { index: component }) if component == index
+            ));
+        }
+        assert_eq!(
+            Path::from_str("capabilities/0").ok(),
+            Some(Path::Capability(CapabilityPath(0)))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(CLIENT_PARAMS, ParamsPath::Client, StoreType::Client)]
+    #[case(CONNECTION_PARAMS, ParamsPath::Connection, StoreType::Connection)]
+    #[case(CHANNEL_PARAMS, ParamsPath::Channel, StoreType::Channel)]
+    fn test_parse_params_fn(
+        #[case] path_str: &str,
+        #[case] params: ParamsPath,
+        #[case] store: StoreType,
+    ) {
+        let path = parse_params(&[path_str]).expect("no error");
+
+        assert_eq!(path, Path::Params(params));
+        assert_eq!(path.to_string(), path_str);
+        assert_eq!(Path::from_str(path_str).expect("no error"), path);
+        assert_eq!(path.store(), store);
+        assert!(path.is_provable());
+
+        assert!(matches!(
+            parse_params(&[path_str, "0"]),
+            Err(PathError::WrongComponentCount {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
 }
@@ -20,7 +20,7 @@ use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    SeqAckPath, SeqRecvPath, SeqSendPath, CHANNEL_END_PREFIX, CLIENT_PREFIX, CONNECTION_PREFIX,
 };
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
@@ -246,7 +246,7 @@ where
 {
     /// Returns the list of all client states.
     fn client_states(&self) -> Result<Vec<(ClientId, ClientStateRef<Self>)>, HostError> {
-        let path = "clients".to_owned().into();
+        let path = CLIENT_PREFIX.to_owned().into();
 
         self.client_state_store
             .get_keys(&path)
@@ -334,7 +334,7 @@ where
 
     /// Returns all the IBC connection ends of a chain.
     fn connection_ends(&self) -> Result<Vec<IdentifiedConnectionEnd>, HostError> {
-        let path = "connections".to_owned().into();
+        let path = CONNECTION_PREFIX.to_owned().into();
 
         self.connection_end_store
             .get_keys(&path)
@@ -376,7 +376,7 @@ where
 
     /// Returns all the IBC channel ends of a chain.
     fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, HostError> {
-        let path = "channelEnds".to_owned().into();
+        let path = CHANNEL_END_PREFIX.to_owned().into();
 
         self.channel_end_store
             .get_keys(&path)